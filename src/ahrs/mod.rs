@@ -0,0 +1,238 @@
+//!Orientation filters (AHRS)
+//!
+//!The filters fuse gyroscope, accelerometer and optional magnetometer samples into the orientation quaternion, which turns sensor-frame vectors into earth-frame vectors. The earth frame has the axis z pointing up, the horizontal component of the magnetic field points along the axis x
+//!
+//!Фильтры ориентации, объединяющие отсчеты гироскопа, акселерометра и необязательного магнитометра в кватернион ориентации, который переводит векторы в системе координат датчика в векторы в земной системе координат. Ось z земной системы координат направлена вверх, горизонтальная составляющая магнитного поля направлена вдоль оси x
+use crate::linalg;
+use crate::quaternion::{QNum, UnitQNum};
+
+//Опорное направление магнитного поля (bx, 0, bz) в земной системе координат
+fn earth_field(q:&QNum, m:(f32, f32, f32)) -> (f32, f32){
+    let h = q.rotate_vector(m);
+    ((h.0*h.0 + h.1*h.1).powf(0.5), h.2)
+}
+
+///Madgwick gradient descent orientation filter
+///
+///Фильтр ориентации Маджвика на основе градиентного спуска
+pub struct Madgwick{
+    q:QNum,
+    beta:f32,
+    zeta:f32,
+    bias:(f32, f32, f32)
+}
+
+impl Madgwick {
+    ///The function for creating a filter with the identity initial orientation, the gain 'beta' of the gradient step and the gain 'zeta' of the gyroscope bias estimation (zero disables the estimation)
+    ///
+    ///Функция для создания фильтра с тождественной начальной ориентацией, коэффициентом 'beta' шага градиента и коэффициентом 'zeta' оценки смещения гироскопа (ноль отключает оценку)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::ahrs::Madgwick;
+    /// let f = Madgwick::make(0.1_f32, 0_f32);
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), f.get().get());
+    /// assert_eq!((0_f32, 0_f32, 0_f32), f.bias());
+    /// ```
+    pub fn make(beta:f32, zeta:f32) -> Self{
+        Madgwick{ q:QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32), beta, zeta, bias:(0_f32, 0_f32, 0_f32) }
+    }
+    ///The method for setting the current orientation
+    ///
+    ///Метод для установки текущей ориентации
+    ///
+    /// # Example
+    ///```
+    /// use tmn::ahrs::Madgwick;
+    /// use tmn::quaternion::{QNum, UnitQNum};
+    /// let mut f = Madgwick::make(0.1_f32, 0_f32);
+    /// f.set(UnitQNum::make(QNum::make_from_r(0_f32, 0_f32, 0_f32, 2_f32)));
+    /// assert_eq!((0_f32, 0_f32, 0_f32, 1_f32), f.get().get());
+    /// ```
+    pub fn set(&mut self, q:UnitQNum){
+        self.q = q.get_q();
+    }
+    ///The method that returns the current orientation
+    ///
+    ///Метод, возвращающий текущую ориентацию
+    pub fn get(&self) -> UnitQNum{ UnitQNum::make(self.q.clone()) }
+    ///The method that returns the current estimation of the gyroscope bias (in radians per second)
+    ///
+    ///Метод, возвращающий текущую оценку смещения гироскопа (в радианах в секунду)
+    pub fn bias(&self) -> (f32, f32, f32){ self.bias }
+    ///The method that updates the orientation by the gyroscope sample 'gyro' (in radians per second), the accelerometer sample 'accel' and the optional magnetometer sample 'mag' (in any units) taken during the time 'dt'. A zero accelerometer or magnetometer sample is ignored
+    ///
+    ///Метод, обновляющий ориентацию по отсчету гироскопа 'gyro' (в радианах в секунду), отсчету акселерометра 'accel' и необязательному отсчету магнитометра 'mag' (в любых единицах), полученным за время 'dt'. Нулевой отсчет акселерометра или магнитометра игнорируется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::ahrs::Madgwick;
+    /// use tmn::quaternion::QNum;
+    /// //Неподвижный наклоненный датчик
+    /// let t = QNum::make_from_a(0.8_f32, (0.6_f32, 0.8_f32, 0_f32)).mult_q(QNum::make_from_a(1_f32, (0_f32, 0_f32, 1_f32)));
+    /// let accel = t.inverse_rotate_vector((0_f32, 0_f32, 9.81_f32));
+    /// let mag = t.inverse_rotate_vector((0.3_f32, 0_f32, -0.4_f32));
+    /// let mut f = Madgwick::make(0.5_f32, 0_f32);
+    /// for _ in 0..2000 {
+    ///     f.update((0_f32, 0_f32, 0_f32), accel, Some(mag), 0.01_f32);
+    /// }
+    /// assert!(f.get().get_q().dot(t).abs() > 0.9999_f32);
+    /// ```
+    /// ```
+    /// use tmn::ahrs::Madgwick;
+    /// use tmn::quaternion::QNum;
+    /// //Вращение с постоянной скоростью и смещенным гироскопом
+    /// let w = (0.1_f32, -0.2_f32, 0.3_f32);
+    /// let b = (0.02_f32, -0.01_f32, 0.015_f32);
+    /// let mut t = QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32);
+    /// let mut f = Madgwick::make(0.1_f32, 0.02_f32);
+    /// for _ in 0..20000 {
+    ///     t = t.integrate_exp(w, 0.01_f32);
+    ///     let accel = t.inverse_rotate_vector((0_f32, 0_f32, 1_f32));
+    ///     let mag = t.inverse_rotate_vector((0.5_f32, 0_f32, -0.5_f32));
+    ///     f.update((w.0+b.0, w.1+b.1, w.2+b.2), accel, Some(mag), 0.01_f32);
+    /// }
+    /// assert!(f.get().get_q().dot(t).abs() > 0.9999_f32);
+    /// let (bx, by, bz) = f.bias();
+    /// assert!((bx-b.0).abs() < 0.002 && (by-b.1).abs() < 0.002 && (bz-b.2).abs() < 0.002);
+    /// ```
+    pub fn update(&mut self, gyro:(f32, f32, f32), accel:(f32, f32, f32), mag:Option<(f32, f32, f32)>, dt:f32){
+        let (q0, q1, q2, q3) = self.q.get();
+        let mut s = [0_f32; 4];
+        if let Some(a) = linalg::v_normalize(accel) {
+            //Невязка и градиент для направления силы тяжести
+            let f1 = 2_f32*(q1*q3 - q0*q2) - a.0;
+            let f2 = 2_f32*(q0*q1 + q2*q3) - a.1;
+            let f3 = 2_f32*(0.5_f32 - q1*q1 - q2*q2) - a.2;
+            s[0] += -2_f32*q2*f1 + 2_f32*q1*f2;
+            s[1] += 2_f32*q3*f1 + 2_f32*q0*f2 - 4_f32*q1*f3;
+            s[2] += -2_f32*q0*f1 + 2_f32*q3*f2 - 4_f32*q2*f3;
+            s[3] += 2_f32*q1*f1 + 2_f32*q2*f2;
+            if let Some(m) = mag.and_then(linalg::v_normalize) {
+                //Невязка и градиент для направления магнитного поля
+                let (bx, bz) = earth_field(&self.q, m);
+                let f4 = 2_f32*bx*(0.5_f32 - q2*q2 - q3*q3) + 2_f32*bz*(q1*q3 - q0*q2) - m.0;
+                let f5 = 2_f32*bx*(q1*q2 - q0*q3) + 2_f32*bz*(q0*q1 + q2*q3) - m.1;
+                let f6 = 2_f32*bx*(q0*q2 + q1*q3) + 2_f32*bz*(0.5_f32 - q1*q1 - q2*q2) - m.2;
+                s[0] += -2_f32*bz*q2*f4 + (-2_f32*bx*q3 + 2_f32*bz*q1)*f5 + 2_f32*bx*q2*f6;
+                s[1] += 2_f32*bz*q3*f4 + (2_f32*bx*q2 + 2_f32*bz*q0)*f5 + (2_f32*bx*q3 - 4_f32*bz*q1)*f6;
+                s[2] += (-4_f32*bx*q2 - 2_f32*bz*q0)*f4 + (2_f32*bx*q1 + 2_f32*bz*q3)*f5 + (2_f32*bx*q0 - 4_f32*bz*q2)*f6;
+                s[3] += (-4_f32*bx*q3 + 2_f32*bz*q1)*f4 + (-2_f32*bx*q0 + 2_f32*bz*q2)*f5 + 2_f32*bx*q1*f6;
+            }
+        }
+        let step = QNum::make_from_r(s[0], s[1], s[2], s[3]).normalize();
+        if self.zeta != 0_f32 {
+            //Ошибка угловой скорости 2·q*·s интегрируется в оценку смещения
+            let (_, ex, ey, ez) = self.q.conj().mult_q(step.clone()).mult_r(2_f32).get();
+            self.bias = linalg::v_add(self.bias, linalg::v_mult_r((ex, ey, ez), self.zeta*dt));
+        }
+        let w = linalg::v_add(gyro, linalg::v_mult_r(self.bias, -1_f32));
+        let dq = self.q.mult_q(QNum::make_from_r(0_f32, w.0, w.1, w.2)).mult_r(0.5_f32).add_q(step.mult_r(-self.beta));
+        self.q = self.q.add_q(dq.mult_r(dt)).normalize();
+    }
+}
+
+///Mahony complementary orientation filter with proportional and integral feedback
+///
+///Комплементарный фильтр ориентации Махони с пропорциональной и интегральной обратной связью
+pub struct Mahony{
+    q:QNum,
+    kp:f32,
+    ki:f32,
+    integral:(f32, f32, f32)
+}
+
+impl Mahony {
+    ///The function for creating a filter with the identity initial orientation, the proportional gain 'kp' and the integral gain 'ki' (zero disables the gyroscope bias estimation)
+    ///
+    ///Функция для создания фильтра с тождественной начальной ориентацией, пропорциональным коэффициентом 'kp' и интегральным коэффициентом 'ki' (ноль отключает оценку смещения гироскопа)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::ahrs::Mahony;
+    /// let f = Mahony::make(1_f32, 0.1_f32);
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), f.get().get());
+    /// ```
+    pub fn make(kp:f32, ki:f32) -> Self{
+        Mahony{ q:QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32), kp, ki, integral:(0_f32, 0_f32, 0_f32) }
+    }
+    ///The method for setting the current orientation
+    ///
+    ///Метод для установки текущей ориентации
+    ///
+    /// # Example
+    ///```
+    /// use tmn::ahrs::Mahony;
+    /// use tmn::quaternion::{QNum, UnitQNum};
+    /// let mut f = Mahony::make(1_f32, 0_f32);
+    /// f.set(UnitQNum::make(QNum::make_from_r(0_f32, 3_f32, 0_f32, 0_f32)));
+    /// assert_eq!((0_f32, 1_f32, 0_f32, 0_f32), f.get().get());
+    /// ```
+    pub fn set(&mut self, q:UnitQNum){
+        self.q = q.get_q();
+    }
+    ///The method that returns the current orientation
+    ///
+    ///Метод, возвращающий текущую ориентацию
+    pub fn get(&self) -> UnitQNum{ UnitQNum::make(self.q.clone()) }
+    ///The method that returns the current estimation of the gyroscope bias (in radians per second), that is the integral feedback with the opposite sign
+    ///
+    ///Метод, возвращающий текущую оценку смещения гироскопа (в радианах в секунду), то есть интегральную обратную связь с обратным знаком
+    pub fn bias(&self) -> (f32, f32, f32){ linalg::v_mult_r(self.integral, -1_f32) }
+    ///The method that updates the orientation by the gyroscope sample 'gyro' (in radians per second), the accelerometer sample 'accel' and the optional magnetometer sample 'mag' (in any units) taken during the time 'dt'. A zero accelerometer or magnetometer sample is ignored
+    ///
+    ///Метод, обновляющий ориентацию по отсчету гироскопа 'gyro' (в радианах в секунду), отсчету акселерометра 'accel' и необязательному отсчету магнитометра 'mag' (в любых единицах), полученным за время 'dt'. Нулевой отсчет акселерометра или магнитометра игнорируется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::ahrs::Mahony;
+    /// use tmn::quaternion::QNum;
+    /// //Неподвижный наклоненный датчик без магнитометра: восстанавливается только наклон
+    /// let t = QNum::make_from_a(0.6_f32, (1_f32, 0_f32, 0_f32));
+    /// let accel = t.inverse_rotate_vector((0_f32, 0_f32, 9.81_f32));
+    /// let mut f = Mahony::make(2_f32, 0_f32);
+    /// for _ in 0..1000 {
+    ///     f.update((0_f32, 0_f32, 0_f32), accel, None, 0.01_f32);
+    /// }
+    /// let (x, y, z) = f.get().rotate_vector(accel);
+    /// assert!(x.abs() < 0.001 && y.abs() < 0.001 && (z-9.81_f32).abs() < 0.001);
+    /// ```
+    /// ```
+    /// use tmn::ahrs::Mahony;
+    /// use tmn::quaternion::QNum;
+    /// //Вращение с постоянной скоростью и смещенным гироскопом
+    /// let w = (-0.3_f32, 0.2_f32, 0.1_f32);
+    /// let b = (0.02_f32, -0.01_f32, 0.015_f32);
+    /// let mut t = QNum::make_from_a(0.5_f32, (0_f32, 1_f32, 0_f32));
+    /// let mut f = Mahony::make(1_f32, 0.3_f32);
+    /// for _ in 0..10000 {
+    ///     t = t.integrate_exp(w, 0.01_f32);
+    ///     let accel = t.inverse_rotate_vector((0_f32, 0_f32, 1_f32));
+    ///     let mag = t.inverse_rotate_vector((0.5_f32, 0_f32, -0.5_f32));
+    ///     f.update((w.0+b.0, w.1+b.1, w.2+b.2), accel, Some(mag), 0.01_f32);
+    /// }
+    /// assert!(f.get().get_q().dot(t).abs() > 0.9999_f32);
+    /// let (bx, by, bz) = f.bias();
+    /// assert!((bx-b.0).abs() < 0.001 && (by-b.1).abs() < 0.001 && (bz-b.2).abs() < 0.001);
+    /// ```
+    pub fn update(&mut self, gyro:(f32, f32, f32), accel:(f32, f32, f32), mag:Option<(f32, f32, f32)>, dt:f32){
+        let mut e = (0_f32, 0_f32, 0_f32);
+        if let Some(a) = linalg::v_normalize(accel) {
+            //Ошибка между измеренным и ожидаемым направлениями силы тяжести
+            let v = self.q.inverse_rotate_vector((0_f32, 0_f32, 1_f32));
+            e = linalg::v_add(e, linalg::v_cross(a, v));
+            if let Some(m) = mag.and_then(linalg::v_normalize) {
+                let (bx, bz) = earth_field(&self.q, m);
+                let w = self.q.inverse_rotate_vector((bx, 0_f32, bz));
+                e = linalg::v_add(e, linalg::v_cross(m, w));
+            }
+        }
+        let mut w = gyro;
+        if self.ki != 0_f32 {
+            self.integral = linalg::v_add(self.integral, linalg::v_mult_r(e, self.ki*dt));
+            w = linalg::v_add(w, self.integral);
+        }
+        w = linalg::v_add(w, linalg::v_mult_r(e, self.kp));
+        self.q = self.q.integrate_exp(w, dt);
+    }
+}
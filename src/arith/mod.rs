@@ -0,0 +1,134 @@
+//!Helper functions for integer arithmetic
+//!
+//!Вспомогательные функции целочисленной арифметики
+
+//Деление с округлением к ближайшему целому (b > 0)
+pub(crate) fn div_round(a:i128, b:i128) -> i128{ (2*a + b).div_euclid(2*b) }
+
+//Целая часть квадратного корня: приближение через f64 с последующей точной поправкой
+pub(crate) fn isqrt(n:u64) -> u64{
+    let mut r = (n as f64).sqrt() as u64;
+    while (r as u128)*(r as u128) > n as u128 {
+        r -= 1;
+    }
+    while ((r + 1) as u128)*((r + 1) as u128) <= n as u128 {
+        r += 1;
+    }
+    r
+}
+
+//Возведение в степень по модулю
+pub(crate) fn mod_pow(a:u64, e:u64, m:u64) -> u64{
+    let (mut a, mut e, mut ret) = ((a%m) as u128, e, 1_u128%(m as u128));
+    while e > 0 {
+        if e&1 == 1 {
+            ret = ret*a%(m as u128);
+        }
+        a = a*a%(m as u128);
+        e >>= 1;
+    }
+    ret as u64
+}
+
+//Проверка простоты натурального числа (детерминированный тест Миллера-Рабина для u64)
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn is_prime(n:u64) -> bool{
+    if n < 2 {
+        return false;
+    }
+    for p in [2_u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n%p == 0 {
+            return n == p;
+        }
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d%2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    'witness: for a in [2_u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = ((x as u128)*(x as u128)%(n as u128)) as u64;
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+//Разложение натурального числа на простые множители: пары (простое, степень) по возрастанию.
+//Пробное деление: медленно, если у n есть два простых множителя больше ~2^26 (большой простой остаток распознается тестом простоты)
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn factor(mut n:u64) -> Vec<(u64, u32)>{
+    let mut ret = Vec::new();
+    let mut p = 2_u64;
+    let mut prime = is_prime(n);
+    while !prime && p <= n/p {
+        let mut e = 0;
+        while n%p == 0 {
+            n /= p;
+            e += 1;
+        }
+        if e > 0 {
+            ret.push((p, e));
+            prime = is_prime(n);
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        ret.push((n, 1));
+    }
+    ret
+}
+
+//Квадратный корень из квадратичного вычета a по нечетному простому модулю p (алгоритм Тонелли-Шенкса)
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn sqrt_mod(a:u64, p:u64) -> u64{
+    let a = a%p;
+    if a == 0 {
+        return 0;
+    }
+    let (mut q, mut s) = (p - 1, 0);
+    while q%2 == 0 {
+        q /= 2;
+        s += 1;
+    }
+    let mut z = 2_u64;
+    while mod_pow(z, (p - 1)/2, p) != p - 1 {
+        z += 1;
+    }
+    let mul = |x:u64, y:u64| ((x as u128)*(y as u128)%(p as u128)) as u64;
+    let (mut m, mut c, mut t, mut r) = (s, mod_pow(z, q, p), mod_pow(a, q, p), mod_pow(a, q.div_ceil(2), p));
+    while t != 1 {
+        let (mut i, mut t2) = (0, t);
+        while t2 != 1 {
+            t2 = mul(t2, t2);
+            i += 1;
+        }
+        let b = mod_pow(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul(b, b);
+        t = mul(t, c);
+        r = mul(r, b);
+    }
+    r
+}
+
+//Решение x² + d·y² = p для простого p, по модулю которого -d - квадратичный вычет (алгоритм Корнаккьи)
+pub(crate) fn cornacchia(d:u64, p:u64) -> (u64, u64){
+    let mut r = sqrt_mod(p - d%p, p);
+    if r > p/2 {
+        r = p - r;
+    }
+    let (mut a, mut b) = (p, r);
+    while (b as u128)*(b as u128) > p as u128 {
+        (a, b) = (b, a%b);
+    }
+    (b, isqrt((p - b*b)/d))
+}
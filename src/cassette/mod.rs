@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod cassette {
     pub fn eq(exp:u8, i:u8) -> bool{
         (exp&(1<<i)) != 0
@@ -0,0 +1,341 @@
+//!Generic Cayley-Dickson construction of hypercomplex algebras
+//!
+//!Обобщенное построение Кэли-Диксона гиперкомплексных алгебр
+use crate::complex::CNum;
+use crate::quaternion::QNum;
+use crate::octonion::ONum;
+
+///The trait of an algebra with conjugation over real numbers, which can be doubled by the Cayley-Dickson construction
+///
+///Трейт алгебры с сопряжением над действительными числами, которую можно удвоить построением Кэли-Диксона
+pub trait Algebra: Clone + PartialEq{
+    ///The dimension of the algebra
+    ///
+    ///Размерность алгебры
+    fn dim() -> usize;
+    ///The zero element
+    ///
+    ///Нулевой элемент
+    fn zero() -> Self;
+    ///The element with the coefficients 'e' (the length of 'e' is equal to the dimension)
+    ///
+    ///Элемент с коэффициентами 'e' (длина 'e' равна размерности)
+    fn from_coefs(e:&[f32]) -> Self;
+    ///The coefficients of the element
+    ///
+    ///Коэффициенты элемента
+    fn coefs(&self) -> Vec<f32>;
+    ///The sum of elements
+    ///
+    ///Сумма элементов
+    fn add_a(&self, v:Self) -> Self;
+    ///The product of the element and a real number
+    ///
+    ///Произведение элемента и действительного числа
+    fn mult_r(&self, v:f32) -> Self;
+    ///The product of elements
+    ///
+    ///Произведение элементов
+    fn mult_a(&self, v:Self) -> Self;
+    ///The conjugate element
+    ///
+    ///Сопряженный элемент
+    fn conj(&self) -> Self;
+    ///The norm (the sum of squares of the coefficients)
+    ///
+    ///Норма (сумма квадратов коэффициентов)
+    fn norm(&self) -> f32{ self.coefs().iter().map(|x| x*x).sum() }
+}
+
+impl Algebra for f32 {
+    fn dim() -> usize{ 1 }
+    fn zero() -> Self{ 0_f32 }
+    fn from_coefs(e:&[f32]) -> Self{ e[0] }
+    fn coefs(&self) -> Vec<f32>{ vec![*self] }
+    fn add_a(&self, v:Self) -> Self{ self + v }
+    fn mult_r(&self, v:f32) -> Self{ self*v }
+    fn mult_a(&self, v:Self) -> Self{ self*v }
+    fn conj(&self) -> Self{ *self }
+}
+
+///The structure storing the element (a, b) = a + b·e of the doubled algebra. The product is (a, b)(c, d) = (ac - d*b, da + bc*), the conjugate is (a, b)* = (a*, -b)
+///
+///Структура, хранящая элемент (a, b) = a + b·e удвоенной алгебры. Произведение (a, b)(c, d) = (ac - d*b, da + bc*), сопряжение (a, b)* = (a*, -b)
+pub struct CayleyDickson<T: Algebra>{a:T, b:T}
+
+///Complex numbers as the doubled real numbers
+///
+///Комплексные числа как удвоенные действительные числа
+pub type Complex = CayleyDickson<f32>;
+///Quaternions as the doubled complex numbers
+///
+///Кватернионы как удвоенные комплексные числа
+pub type Quaternion = CayleyDickson<Complex>;
+///Octonions as the doubled quaternions
+///
+///Октонионы как удвоенные кватернионы
+pub type Octonion = CayleyDickson<Quaternion>;
+///Sedenions as the doubled octonions. They are not a division algebra: there are zero divisors
+///
+///Седенионы как удвоенные октонионы. Они не являются алгеброй с делением: в них есть делители нуля
+pub type Sedenion = CayleyDickson<Octonion>;
+
+impl<T: Algebra> Clone for CayleyDickson<T> {
+    fn clone(&self) -> Self{ CayleyDickson{a:self.a.clone(), b:self.b.clone()} }
+}
+
+impl<T: Algebra> PartialEq for CayleyDickson<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+}
+
+impl<T: Algebra> Algebra for CayleyDickson<T> {
+    fn dim() -> usize{ 2*T::dim() }
+    fn zero() -> Self{ CayleyDickson{a:T::zero(), b:T::zero()} }
+    fn from_coefs(e:&[f32]) -> Self{
+        let n = T::dim();
+        CayleyDickson{a:T::from_coefs(&e[..n]), b:T::from_coefs(&e[n..2*n])}
+    }
+    fn coefs(&self) -> Vec<f32>{
+        let mut ret = self.a.coefs();
+        ret.extend(self.b.coefs());
+        ret
+    }
+    fn add_a(&self, v:Self) -> Self{ CayleyDickson{a:self.a.add_a(v.a), b:self.b.add_a(v.b)} }
+    fn mult_r(&self, v:f32) -> Self{ CayleyDickson{a:self.a.mult_r(v), b:self.b.mult_r(v)} }
+    fn mult_a(&self, v:Self) -> Self{
+        let (a, b) = (&self.a, &self.b);
+        let (c, d) = (v.a, v.b);
+        CayleyDickson{
+            a:a.mult_a(c.clone()).add_a(d.conj().mult_a(b.clone()).mult_r(-1_f32)),
+            b:d.mult_a(a.clone()).add_a(b.mult_a(c.conj()))
+        }
+    }
+    fn conj(&self) -> Self{ CayleyDickson{a:self.a.conj(), b:self.b.mult_r(-1_f32)} }
+}
+
+impl<T: Algebra> CayleyDickson<T> {
+    ///The function that creates the element (a, b) = a + b·e from 2 elements of the halved algebra
+    ///
+    ///Функция, создающая элемент (a, b) = a + b·e из 2 элементов алгебры половинной размерности
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Complex, Quaternion};
+    /// let q = Quaternion::make(Complex::make(1_f32, 2_f32), Complex::make(3_f32, 4_f32));
+    /// assert_eq!(vec![1_f32, 2_f32, 3_f32, 4_f32], q.coefs());
+    /// assert_eq!(4, Quaternion::dim());
+    /// ```
+    pub fn make(a:T, b:T) -> Self{ CayleyDickson{a, b} }
+    ///The function that creates the element with the coefficients 'e' (the length of 'e' is equal to the dimension)
+    ///
+    ///Функция, создающая элемент с коэффициентами 'e' (длина 'e' равна размерности)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Sedenion};
+    /// let e: Vec<f32> = (0..16).map(|n| n as f32).collect();
+    /// assert_eq!(e, Sedenion::make_from_r(&e).coefs());
+    /// ```
+    pub fn make_from_r(e:&[f32]) -> Self{
+        assert_eq!(Self::dim(), e.len());
+        Self::from_coefs(e)
+    }
+    ///The function that returns the basis element e_n
+    ///
+    ///Функция, возвращающая базисный элемент e_n
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Octonion};
+    /// //e1·e2 = e3, e1·e4 = e5
+    /// assert!(Octonion::basis(1).mult_a(Octonion::basis(2)) == Octonion::basis(3));
+    /// assert!(Octonion::basis(1).mult_a(Octonion::basis(4)) == Octonion::basis(5));
+    /// //e_n·e_n = -1
+    /// assert!(Octonion::basis(6).mult_a(Octonion::basis(6)) == Octonion::basis(0).mult_r(-1_f32));
+    /// ```
+    pub fn basis(n:usize) -> Self{
+        let mut e = vec![0_f32; Self::dim()];
+        e[n] = 1_f32;
+        Self::from_coefs(&e)
+    }
+    ///The method that returns the pair (a, b) of the element a + b·e
+    ///
+    ///Метод, возвращающий пару (a, b) элемента a + b·e
+    pub fn get(&self) -> (T, T){ (self.a.clone(), self.b.clone()) }
+    ///The method that returns the module of the element
+    ///
+    ///Метод, возвращающий модуль элемента
+    pub fn modl(&self) -> f32{ self.norm().powf(0.5) }
+    ///The method that returns the inverse element x*/|x|². For sedenions it is only the two-sided inverse in the sense x·x⁻¹ = x⁻¹·x = 1
+    ///
+    ///Метод, возвращающий обратный элемент x*/|x|². Для седенионов это только двусторонний обратный в смысле x·x⁻¹ = x⁻¹·x = 1
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Sedenion};
+    /// let e: Vec<f32> = (0..16).map(|n| 1_f32 + (n as f32)*0.25_f32).collect();
+    /// let x = Sedenion::make_from_r(&e);
+    /// let p = x.mult_a(x.inv()).coefs();
+    /// assert!((p[0]-1_f32).abs() < 0.000001);
+    /// assert!(p[1..].iter().all(|c| c.abs() < 0.000001));
+    /// ```
+    pub fn inv(&self) -> Self{ self.conj().mult_r(1_f32/self.norm()) }
+    ///The method that returns the real 2^n x 2^n matrix L(x) of the left multiplication by the element: L(x)·y = x·y, where elements are columns of coefficients
+    ///
+    ///Метод, возвращающий действительную матрицу 2^n x 2^n L(x) левого умножения на элемент: L(x)·y = x·y, где элементы - столбцы коэффициентов
+    #[allow(clippy::needless_range_loop)]
+    pub fn left_matrix(&self) -> Vec<Vec<f32>>{
+        let n = Self::dim();
+        let mut ret = vec![vec![0_f32; n]; n];
+        for c in 0..n {
+            let col = self.mult_a(Self::basis(c)).coefs();
+            for (r, v) in col.into_iter().enumerate() {
+                ret[r][c] = v;
+            }
+        }
+        ret
+    }
+    ///The method that looks for a zero divisor pair: a unit element y with x·y = 0 (up to the tolerance 'eps'). Such an element exists if the left multiplication matrix of x is singular. In the complex numbers, quaternions and octonions only zero has zero divisors, in the sedenions there are non-zero ones
+    ///
+    ///Метод, ищущий пару делителей нуля: единичный элемент y, такой что x·y = 0 (с точностью 'eps'). Такой элемент существует, если матрица левого умножения на x вырождена. В комплексных числах, кватернионах и октонионах делители нуля есть только у нуля, в седенионах есть ненулевые
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Octonion, Sedenion};
+    /// let e = |n| Sedenion::basis(n);
+    /// //(e3 + e10)·(e6 - e15) = 0
+    /// let x = e(3).add_a(e(10));
+    /// let y = e(6).add_a(e(15).mult_r(-1_f32));
+    /// assert!(x.mult_a(y).norm() == 0_f32);
+    /// //Поиск делителя нуля
+    /// let z = x.zero_divisor(0.0001_f32).unwrap();
+    /// assert!((z.norm()-1_f32).abs() < 0.0001);
+    /// assert!(x.mult_a(z).norm() < 0.000001);
+    /// //У ненулевых октонионов и у базисных седенионов делителей нуля нет
+    /// let o = Octonion::basis(3).add_a(Octonion::basis(6));
+    /// assert!(o.zero_divisor(0.0001_f32).is_none());
+    /// assert!(Sedenion::basis(1).zero_divisor(0.0001_f32).is_none());
+    /// ```
+    #[allow(clippy::needless_range_loop)]
+    pub fn zero_divisor(&self, eps:f32) -> Option<Self>{
+        let n = Self::dim();
+        let mut m: Vec<Vec<f64>> = self.left_matrix().into_iter().map(|r| r.into_iter().map(|v| v as f64).collect()).collect();
+        let scale = (self.norm() as f64).sqrt();
+        if scale == 0_f64 {
+            return Some(Self::basis(0));
+        }
+        //Приведение к ступенчатому виду с выбором главного элемента
+        let mut pivots: Vec<usize> = Vec::new();
+        let mut free: Option<usize> = None;
+        let mut row = 0;
+        for c in 0..n {
+            let p = (row..n).max_by(|a, b| m[*a][c].abs().total_cmp(&m[*b][c].abs()));
+            match p {
+                Some(p) if m[p][c].abs() > (eps as f64)*scale => {
+                    m.swap(row, p);
+                    for r in 0..n {
+                        if r != row {
+                            let f = m[r][c]/m[row][c];
+                            for k in c..n {
+                                m[r][k] -= f*m[row][k];
+                            }
+                        }
+                    }
+                    pivots.push(c);
+                    row += 1;
+                },
+                _ => {
+                    if free.is_none() {
+                        free = Some(c);
+                    }
+                }
+            }
+        }
+        //Вектор ядра: свободная переменная равна 1
+        let f = free?;
+        let mut y = vec![0_f64; n];
+        y[f] = 1_f64;
+        for (r, c) in pivots.iter().enumerate() {
+            y[*c] = -m[r][f]/m[r][*c];
+        }
+        let l = y.iter().map(|v| v*v).sum::<f64>().sqrt();
+        let y: Vec<f32> = y.iter().map(|v| (v/l) as f32).collect();
+        Some(Self::from_coefs(&y))
+    }
+}
+
+impl Complex {
+    ///The function that creates the complex number r + i·i as a Cayley-Dickson pair
+    ///
+    ///Функция, создающая комплексное число r + i·i в виде пары Кэли-Диксона
+    pub fn make_from_cnum(c:CNum) -> Self{
+        let (r, i) = c.get();
+        CayleyDickson{a:r, b:i}
+    }
+    ///The method that converts the element to CNum
+    ///
+    ///Метод, преобразующий элемент в CNum
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Complex};
+    /// use tmn::complex::CNum;
+    /// let (a, b) = (CNum::make(3_f32, 2_f32), CNum::make(5_f32, 3_f32));
+    /// let c = Complex::make_from_cnum(a.clone()).mult_a(Complex::make_from_cnum(b.clone()));
+    /// assert!(c.to_cnum() == a.mult_c(b));
+    /// ```
+    pub fn to_cnum(&self) -> CNum{ CNum::make(self.a, self.b) }
+}
+
+impl Quaternion {
+    ///The function that creates the quaternion as a Cayley-Dickson pair (the same as 'QNum::make_from_c')
+    ///
+    ///Функция, создающая кватернион в виде пары Кэли-Диксона (так же, как 'QNum::make_from_c')
+    pub fn make_from_qnum(q:QNum) -> Self{
+        let (r, i, j, k) = q.get();
+        Self::from_coefs(&[r, i, j, k])
+    }
+    ///The method that converts the element to QNum
+    ///
+    ///Метод, преобразующий элемент в QNum
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Quaternion};
+    /// use tmn::quaternion::QNum;
+    /// let p = QNum::make_from_r(1_f32, 2_f32, -1_f32, 3_f32);
+    /// let q = QNum::make_from_r(-2_f32, 1_f32, 4_f32, 1_f32);
+    /// let c = Quaternion::make_from_qnum(p.clone()).mult_a(Quaternion::make_from_qnum(q.clone()));
+    /// assert!(c.to_qnum() == p.mult_q(q));
+    /// ```
+    pub fn to_qnum(&self) -> QNum{
+        let e = self.coefs();
+        QNum::make_from_r(e[0], e[1], e[2], e[3])
+    }
+}
+
+impl Octonion {
+    ///The function that creates the octonion as a Cayley-Dickson pair
+    ///
+    ///Функция, создающая октонион в виде пары Кэли-Диксона
+    pub fn make_from_onum(o:ONum) -> Self{ Self::from_coefs(&o.get()) }
+    ///The method that converts the element to ONum
+    ///
+    ///Метод, преобразующий элемент в ONum
+    ///
+    /// # Example
+    ///```
+    /// use tmn::cayley_dickson::{Algebra, Octonion};
+    /// use tmn::octonion::ONum;
+    /// let x = ONum::make_from_r([1_f32, -2_f32, 0.5_f32, 3_f32, -1_f32, 2_f32, 1_f32, -0.5_f32]);
+    /// let y = ONum::make_from_r([0.5_f32, 1_f32, -1_f32, 2_f32, 3_f32, -2_f32, 0.5_f32, 1_f32]);
+    /// let c = Octonion::make_from_onum(x.clone()).mult_a(Octonion::make_from_onum(y.clone()));
+    /// assert!(c.to_onum() == x.mult_o(y));
+    /// ```
+    pub fn to_onum(&self) -> ONum{
+        let e = self.coefs();
+        ONum::make_from_r([e[0], e[1], e[2], e[3], e[4], e[5], e[6], e[7]])
+    }
+}
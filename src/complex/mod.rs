@@ -57,6 +57,7 @@ impl CNum {
     /// let c = a.clone();
     /// assert!(CNum::make(3_f32, 4_f32)==c);
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn clone(&self) -> CNum{ CNum{r:self.r,i:self.i} }
     /// The method that returns a tuple consisting of the real and imaginary parts of a complex number
     ///
@@ -92,6 +93,42 @@ impl CNum {
     /// assert_eq!(5_f32, a.modl());
     /// ```
     pub fn modl(&self) -> f32{self.mult_c(self.conj()).r.powf(0.5) }
+    ///The method that returns the argument (phase) of a complex number in radians, from -PI to PI
+    ///
+    ///Метод, возвращающий аргумент (фазу) комплексного числа в радианах, от -PI до PI
+    ///
+    /// # Example
+    ///```
+    /// use tmn::complex::CNum;
+    /// let a = CNum::make(0_f32, -2_f32);
+    /// assert_eq!(-std::f32::consts::PI/2_f32, a.arg());
+    /// ```
+    pub fn arg(&self) -> f32{ self.i.atan2(self.r) }
+    ///The function that returns the continuous phase of the sequence of complex numbers 'cs': the arguments are shifted by multiples of 2*PI so that neighbouring phases differ by no more than PI
+    ///
+    ///Функция, возвращающая непрерывную фазу последовательности комплексных чисел 'cs': аргументы сдвигаются на числа, кратные 2*PI, так, чтобы соседние фазы отличались не больше чем на PI
+    ///
+    /// # Example
+    ///```
+    /// use tmn::complex::CNum;
+    /// let cs: Vec<CNum> = (0..20).map(|n| CNum::make((n as f32).cos(), (n as f32).sin())).collect();
+    /// let ph = CNum::unwrap_phase(&cs);
+    /// for (n, p) in ph.into_iter().enumerate() {
+    ///     assert!((p-n as f32).abs() < 0.0001);
+    /// }
+    /// ```
+    pub fn unwrap_phase(cs:&[CNum]) -> Vec<f32>{
+        let pi2 = 2_f32*std::f32::consts::PI;
+        let mut ret: Vec<f32> = Vec::with_capacity(cs.len());
+        for c in cs {
+            let a = c.arg();
+            match ret.last() {
+                Some(prev) => ret.push(a + pi2*((prev - a)/pi2).round()),
+                None => ret.push(a)
+            }
+        }
+        ret
+    }
     ///The method that returns the sum of a complex and a real number
     ///
     /// Метод, возвращающий сумму комплексного и действительного числа
@@ -178,6 +215,40 @@ impl CNum {
         let numerator = self.mult_c(v.conj());
         numerator.mult_r(1_f32/divisor)
     }
+    ///The method that returns the complex number a + b·i as the 2x2 real matrix [[a, -b], [b, a]]. The product of complex numbers corresponds to the product of matrices
+    ///
+    ///Метод, возвращающий комплексное число a + b·i в виде действительной матрицы 2x2 [[a, -b], [b, a]]. Произведению комплексных чисел соответствует произведение матриц
+    ///
+    /// # Example
+    /// ```
+    /// use tmn::complex::CNum;
+    /// let a = CNum::make(3_f32, 2_f32);
+    /// let b = CNum::make(5_f32, -3_f32);
+    /// let (ma, mb) = (a.to_real_matrix(), b.to_real_matrix());
+    /// let mab = a.mult_c(b).to_real_matrix();
+    /// for r in 0..2 {
+    ///     for c in 0..2 {
+    ///         assert_eq!(mab[r][c], ma[r][0]*mb[0][c] + ma[r][1]*mb[1][c]);
+    ///     }
+    /// }
+    /// ```
+    pub fn to_real_matrix(&self) -> [[f32; 2]; 2]{ [[self.r, -self.i], [self.i, self.r]] }
+    ///The function that creates a complex number from the 2x2 real matrix 'm'. It is the inverse of 'to_real_matrix'. If the matrix does not have the form [[a, -b], [b, a]], the nearest such matrix is used
+    ///
+    ///Функция, создающая комплексное число из действительной матрицы 2x2 'm'. Является обратной к 'to_real_matrix'. Если матрица не имеет вида [[a, -b], [b, a]], используется ближайшая матрица такого вида
+    ///
+    /// # Example
+    /// ```
+    /// use tmn::complex::CNum;
+    /// let a = CNum::make(-1_f32, 2.5_f32);
+    /// assert!(CNum::from_real_matrix(a.to_real_matrix()) == a);
+    /// ```
+    pub fn from_real_matrix(m:[[f32; 2]; 2]) -> CNum{
+        CNum{
+            r:(m[0][0] + m[1][1])/2_f32,
+            i:(m[1][0] - m[0][1])/2_f32
+        }
+    }
     /// The method for raising a complex number to a power. Degrees less than one (roots) are counted with k = 0
     ///
     /// Метод для возведения комплексного числа в степень. Степени меньше единицы (корни) считаются с k = 0
@@ -0,0 +1,620 @@
+//!Directional distributions on the circle and on rotations
+//!
+//!Распределения направлений на окружности и на поворотах
+use std::f64::consts::PI;
+use crate::complex::CNum;
+use crate::linalg;
+use crate::quaternion::{QNum, UnitQNum};
+use crate::random::Rng;
+
+//Модифицированная функция Бесселя первого рода I_n(x)·exp(-|x|)
+fn bessel_ie(n:i32, x:f64) -> f64{
+    let sign = if x < 0_f64 && n%2 != 0 { -1_f64 } else { 1_f64 };
+    let x = x.abs();
+    if x <= 30_f64 {
+        let mut term = (x/2_f64).powi(n)*(-x).exp();
+        for k in 1..=n {
+            term /= k as f64;
+        }
+        let mut sum = term;
+        for k in 1..200 {
+            term *= x*x/4_f64/(k as f64*(k + n) as f64);
+            sum += term;
+            if term < sum*1e-17 {
+                break;
+            }
+        }
+        return sign*sum;
+    }
+    //Асимптотическое разложение для больших аргументов
+    let mu = 4_f64*(n*n) as f64;
+    let mut term = 1_f64;
+    let mut sum = 1_f64;
+    for k in 1..10 {
+        let j = (2*k - 1) as f64;
+        term *= -(mu - j*j)/(k as f64*8_f64*x);
+        sum += term;
+    }
+    sign*sum/(2_f64*PI*x).powf(0.5)
+}
+
+//Отношение I_{n+1}(x)/I_n(x)
+fn bessel_ratio(n:i32, x:f64) -> f64{
+    if x == 0_f64 {
+        return 0_f64;
+    }
+    bessel_ie(n + 1, x)/bessel_ie(n, x)
+}
+
+//Обращение A(k) = I_{n+1}(k)/I_n(k) = r для распределения на сфере размерности p = 2n + 2
+fn invert_ratio(n:i32, r:f64) -> f64{
+    let p = (2*n + 2) as f64;
+    let r = r.clamp(0_f64, 0.999999_f64);
+    let mut k = r*(p - r*r)/(1_f64 - r*r);
+    for _ in 0..50 {
+        let a = bessel_ratio(n, k);
+        let da = 1_f64 - a*a - (p - 1_f64)/k*a;
+        if da <= 0_f64 || k <= 0_f64 {
+            break;
+        }
+        let step = (a - r)/da;
+        k = (k - step).max(k/10_f64);
+        if step.abs() < 1e-10_f64*k {
+            break;
+        }
+    }
+    k
+}
+
+//Решение системы 3x3 методом Крамера
+fn solve3(a:&[[f64; 3]; 3], b:[f64; 3]) -> Option<[f64; 3]>{
+    let det = |m:&[[f64; 3]; 3]| m[0][0]*(m[1][1]*m[2][2] - m[1][2]*m[2][1])
+        - m[0][1]*(m[1][0]*m[2][2] - m[1][2]*m[2][0])
+        + m[0][2]*(m[1][0]*m[2][1] - m[1][1]*m[2][0]);
+    let d = det(a);
+    if d == 0_f64 || !d.is_finite() {
+        return None;
+    }
+    let mut x = [0_f64; 3];
+    for (c, v) in x.iter_mut().enumerate() {
+        let mut m = *a;
+        for r in 0..3 {
+            m[r][c] = b[r];
+        }
+        *v = det(&m)/d;
+    }
+    Some(x)
+}
+
+//Нормирующая константа распределения Бингама на S3 (интеграл по поверхностной мере)
+//и моменты E[x_i^2], умноженные на нее. Константа делится на exp(z_max)
+fn bingham_integrals(z:[f64; 4]) -> (f64, [f64; 4]){
+    let zmax = z.iter().cloned().fold(f64::MIN, f64::max);
+    let z = z.map(|v| v - zmax);
+    //Интеграл по двум угловым координатам вычисляется через функции Бесселя,
+    //по оставшейся координате u = sin^2 - методом Симпсона
+    let pair = |za:f64, zb:f64, w:f64| -> (f64, f64, f64){
+        let y = (za - zb)*w/2_f64;
+        let e = ((za + zb)*w/2_f64 + y.abs()).exp();
+        let i0 = bessel_ie(0, y)*e;
+        let i1 = bessel_ie(1, y)*e;
+        (2_f64*PI*i0, PI*w*(i0 + i1), PI*w*(i0 - i1))
+    };
+    let n = 2000;
+    let mut f = 0_f64;
+    let mut m = [0_f64; 4];
+    for s in 0..=n {
+        let u = s as f64/n as f64;
+        let k = if s == 0 || s == n { 1_f64 } else if s%2 == 1 { 4_f64 } else { 2_f64 };
+        let (g01, g0, g1) = pair(z[0], z[1], 1_f64 - u);
+        let (g23, g2, g3) = pair(z[2], z[3], u);
+        f += k*g01*g23;
+        m[0] += k*g0*g23;
+        m[1] += k*g1*g23;
+        m[2] += k*g01*g2;
+        m[3] += k*g01*g3;
+    }
+    let h = 1_f64/(6_f64*n as f64);
+    (f*h, m.map(|v| v*h))
+}
+
+///Von Mises distribution on the unit circle with the mean direction 'mu' (in radians) and the concentration 'kappa'. The samples are unit complex numbers
+///
+///Распределение фон Мизеса на единичной окружности со средним направлением 'mu' (в радианах) и концентрацией 'kappa'. Выборки являются единичными комплексными числами
+pub struct VonMises{
+    mu:f32,
+    kappa:f32
+}
+
+impl VonMises {
+    ///The function for creating the distribution
+    ///
+    ///Функция для создания распределения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::VonMises;
+    /// let d = VonMises::make(1_f32, 4_f32);
+    /// assert_eq!((1_f32, 4_f32), d.get());
+    /// ```
+    pub fn make(mu:f32, kappa:f32) -> Self{ VonMises{mu, kappa:kappa.max(0_f32)} }
+    ///The method that returns the mean direction and the concentration
+    ///
+    ///Метод, возвращающий среднее направление и концентрацию
+    pub fn get(&self) -> (f32, f32){ (self.mu, self.kappa) }
+    ///The method that returns the probability density (with respect to the angle) at the point 'c' of the unit circle. Only the argument of 'c' is used
+    ///
+    ///Метод, возвращающий плотность вероятности (по углу) в точке 'c' единичной окружности. Используется только аргумент 'c'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::complex::CNum;
+    /// use tmn::directional::VonMises;
+    /// let d = VonMises::make(0.5_f32, 3_f32);
+    /// let n = 1000;
+    /// let s: f32 = (0..n).map(|k| {
+    ///     let a = 2_f32*std::f32::consts::PI*k as f32/n as f32;
+    ///     d.pdf(CNum::make(a.cos(), a.sin()))
+    /// }).sum::<f32>()*2_f32*std::f32::consts::PI/n as f32;
+    /// assert!((s-1_f32).abs() < 0.0001);
+    /// ```
+    pub fn pdf(&self, c:CNum) -> f32{
+        let k = self.kappa as f64;
+        let d = (c.arg() - self.mu) as f64;
+        ((k*(d.cos() - 1_f64)).exp()/(2_f64*PI*bessel_ie(0, k))) as f32
+    }
+    ///The method that returns a random point of the unit circle (the Best-Fisher method)
+    ///
+    ///Метод, возвращающий случайную точку единичной окружности (метод Беста-Фишера)
+    pub fn sample(&self, rng:&mut Rng) -> CNum{
+        let k = self.kappa as f64;
+        if k < 1e-6_f64 {
+            return rng.unit_complex();
+        }
+        let tau = 1_f64 + (1_f64 + 4_f64*k*k).powf(0.5);
+        let rho = (tau - (2_f64*tau).powf(0.5))/(2_f64*k);
+        let r = (1_f64 + rho*rho)/(2_f64*rho);
+        let f = loop {
+            let z = (PI*rng.next_f32() as f64).cos();
+            let f = (1_f64 + r*z)/(r + z);
+            let c = k*(r - f);
+            let u = (1_f32 - rng.next_f32()) as f64;
+            if c*(2_f64 - c) - u > 0_f64 || (c/u).ln() + 1_f64 - c >= 0_f64 {
+                break f;
+            }
+        };
+        let a = f.clamp(-1_f64, 1_f64).acos() as f32;
+        let a = if rng.next_f32() < 0.5_f32 { self.mu - a } else { self.mu + a };
+        CNum::make(a.cos(), a.sin())
+    }
+    ///The function that returns the maximum likelihood estimation of the distribution from the points 'cs' (only their arguments are used)
+    ///
+    ///Функция, возвращающая оценку распределения по методу максимального правдоподобия по точкам 'cs' (используются только их аргументы)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::VonMises;
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(10);
+    /// let d = VonMises::make(2_f32, 5_f32);
+    /// let cs: Vec<_> = (0..5000).map(|_| d.sample(&mut rng)).collect();
+    /// let (mu, kappa) = VonMises::fit(&cs).get();
+    /// assert!((mu-2_f32).abs() < 0.03 && (kappa-5_f32).abs() < 0.3);
+    /// ```
+    pub fn fit(cs:&[CNum]) -> VonMises{
+        assert!(!cs.is_empty());
+        let (mut x, mut y) = (0_f64, 0_f64);
+        for c in cs {
+            let a = c.arg() as f64;
+            x += a.cos();
+            y += a.sin();
+        }
+        let n = cs.len() as f64;
+        let r = (x*x + y*y).powf(0.5)/n;
+        VonMises{mu:y.atan2(x) as f32, kappa:invert_ratio(0, r) as f32}
+    }
+}
+
+///Von Mises-Fisher distribution on unit quaternions (the sphere S3) with the mean direction 'mu' and the concentration 'kappa'. The density is given with respect to the surface measure of S3, whose total area is 2·PI²
+///
+///Распределение фон Мизеса-Фишера на единичных кватернионах (сфере S3) со средним направлением 'mu' и концентрацией 'kappa'. Плотность задается относительно поверхностной меры S3, полная площадь которой равна 2·PI²
+pub struct VonMisesFisher{
+    mu:UnitQNum,
+    kappa:f32
+}
+
+impl VonMisesFisher {
+    ///The function for creating the distribution
+    ///
+    ///Функция для создания распределения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::VonMisesFisher;
+    /// use tmn::quaternion::UnitQNum;
+    /// let d = VonMisesFisher::make(UnitQNum::make_identity(), 10_f32);
+    /// assert_eq!(10_f32, d.get().1);
+    /// ```
+    pub fn make(mu:UnitQNum, kappa:f32) -> Self{ VonMisesFisher{mu, kappa:kappa.max(0_f32)} }
+    ///The method that returns the mean direction and the concentration
+    ///
+    ///Метод, возвращающий среднее направление и концентрацию
+    pub fn get(&self) -> (UnitQNum, f32){ (self.mu.clone(), self.kappa) }
+    ///The method that returns the probability density at the unit quaternion 'q'
+    ///
+    ///Метод, возвращающий плотность вероятности в единичном кватернионе 'q'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::VonMisesFisher;
+    /// use tmn::quaternion::UnitQNum;
+    /// //Равномерное распределение
+    /// let d = VonMisesFisher::make(UnitQNum::make_identity(), 0_f32);
+    /// let p = d.pdf(UnitQNum::make_from_a(1_f32, (0_f32, 1_f32, 0_f32)));
+    /// assert!((p-1_f32/(2_f32*std::f32::consts::PI.powf(2_f32))).abs() < 0.000001);
+    /// ```
+    pub fn pdf(&self, q:UnitQNum) -> f32{
+        let k = self.kappa as f64;
+        if k < 1e-6_f64 {
+            return (1_f64/(2_f64*PI*PI)) as f32;
+        }
+        let d = self.mu.get_q().dot(q.get_q()) as f64;
+        (k*(k*(d - 1_f64)).exp()/(4_f64*PI*PI*bessel_ie(1, k))) as f32
+    }
+    ///The method that returns a random unit quaternion (Wood's method)
+    ///
+    ///Метод, возвращающий случайный единичный кватернион (метод Вуда)
+    pub fn sample(&self, rng:&mut Rng) -> UnitQNum{
+        let k = self.kappa as f64;
+        if k < 1e-6_f64 {
+            return rng.unit_quaternion();
+        }
+        let b = (-2_f64*k + (4_f64*k*k + 9_f64).powf(0.5))/3_f64;
+        let x0 = (1_f64 - b)/(1_f64 + b);
+        let c = k*x0 + 3_f64*(1_f64 - x0*x0).ln();
+        let w = loop {
+            //Бета-распределение (3/2, 3/2) через два хи-квадрат распределения с 3 степенями свободы
+            let a: f64 = (0..3).map(|_| (rng.gauss() as f64).powi(2)).sum();
+            let e: f64 = (0..3).map(|_| (rng.gauss() as f64).powi(2)).sum();
+            let z = a/(a + e);
+            let w = (1_f64 - (1_f64 + b)*z)/(1_f64 - (1_f64 - b)*z);
+            let u = (1_f32 - rng.next_f32()) as f64;
+            if k*w + 3_f64*(1_f64 - x0*w).ln() - c >= u.ln() {
+                break w;
+            }
+        };
+        let v = linalg::v_mult_r(rng.unit_vector(), (1_f64 - w*w).max(0_f64).powf(0.5) as f32);
+        UnitQNum::make(self.mu.get_q().mult_q(QNum::make_from_r(w as f32, v.0, v.1, v.2)))
+    }
+    ///The function that returns the maximum likelihood estimation of the distribution from the unit quaternions 'qs'. The signs of the quaternions are first aligned with their eigenvector average, so samples of rotations may contain both 'q' and '-q'
+    ///
+    ///Функция, возвращающая оценку распределения по методу максимального правдоподобия по единичным кватернионам 'qs'. Знаки кватернионов предварительно выравниваются по их среднему, найденному методом собственных векторов, поэтому выборки поворотов могут содержать как 'q', так и '-q'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::VonMisesFisher;
+    /// use tmn::quaternion::UnitQNum;
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(11);
+    /// let mu = UnitQNum::make_from_a(1_f32, (1_f32, 0_f32, 1_f32));
+    /// let d = VonMisesFisher::make(mu.clone(), 20_f32);
+    /// let qs: Vec<_> = (0..5000).map(|n| if n%2 == 0 { d.sample(&mut rng) } else { -d.sample(&mut rng) }).collect();
+    /// let (m, kappa) = VonMisesFisher::fit(&qs).get();
+    /// assert!(m.get_q().dot(mu.get_q()) > 0.999_f32);
+    /// assert!((kappa-20_f32).abs() < 1_f32);
+    /// ```
+    pub fn fit(qs:&[UnitQNum]) -> VonMisesFisher{
+        let raw: Vec<QNum> = qs.iter().map(|q| q.get_q()).collect();
+        let (mean, _) = QNum::average(&raw, &[]);
+        let mut s = QNum::make_zero();
+        for q in raw {
+            s = s.add_q(q.canonicalize_to(mean.clone()));
+        }
+        let r = (s.modl()/qs.len() as f32) as f64;
+        VonMisesFisher{mu:UnitQNum::make(s), kappa:invert_ratio(1, r) as f32}
+    }
+}
+
+///Bingham distribution on unit quaternions (the sphere S3) with the density proportional to exp(sum(z_i·(m_i·q)²)). The vectors m_i are orthonormal, the concentrations satisfy 0 = z_0 >= z_1 >= z_2 >= z_3, so m_0 is the mode. The distribution is antipodally symmetric, so it is suitable for rotations. The density is given with respect to the surface measure of S3
+///
+///Распределение Бингама на единичных кватернионах (сфере S3) с плотностью, пропорциональной exp(sum(z_i·(m_i·q)²)). Векторы m_i ортонормированы, концентрации удовлетворяют 0 = z_0 >= z_1 >= z_2 >= z_3, поэтому m_0 является модой. Распределение антиподально симметрично, поэтому подходит для поворотов. Плотность задается относительно поверхностной меры S3
+pub struct Bingham{
+    m:[[f32; 4]; 4],
+    z:[f32; 4],
+    norm:f64
+}
+
+impl Bingham {
+    ///The function for creating the distribution from the orthonormal vectors 'm' (the rows of the matrix) and the concentrations 'z'. The vectors are sorted by descending concentrations, which are shifted so that the largest is zero
+    ///
+    ///Функция для создания распределения из ортонормированных векторов 'm' (строк матрицы) и концентраций 'z'. Векторы упорядочиваются по убыванию концентраций, которые сдвигаются так, чтобы наибольшая была равна нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::Bingham;
+    /// let m = [[0_f32, 1_f32, 0_f32, 0_f32], [1_f32, 0_f32, 0_f32, 0_f32], [0_f32, 0_f32, 1_f32, 0_f32], [0_f32, 0_f32, 0_f32, 1_f32]];
+    /// let d = Bingham::make(m, [-5_f32, 1_f32, -2_f32, -1_f32]);
+    /// let (m, z) = d.get();
+    /// assert_eq!([0_f32, -2_f32, -3_f32, -6_f32], z);
+    /// assert_eq!([1_f32, 0_f32, 0_f32, 0_f32], m[0]);
+    /// ```
+    pub fn make(m:[[f32; 4]; 4], z:[f32; 4]) -> Self{
+        let mut order = [0_usize, 1, 2, 3];
+        order.sort_by(|a, b| z[*b].total_cmp(&z[*a]));
+        let zmax = z[order[0]];
+        let m = order.map(|o| m[o]);
+        let z = order.map(|o| z[o] - zmax);
+        let (norm, _) = bingham_integrals(z.map(|v| v as f64));
+        Bingham{m, z, norm}
+    }
+    fn make_from_matrix(a:&[[f32; 4]; 4]) -> Self{
+        let (vals, vecs) = linalg::sym_eigen(a);
+        Bingham::make(vecs, vals)
+    }
+    ///The method that returns the vectors m_i (the rows of the matrix) and the concentrations z_i
+    ///
+    ///Метод, возвращающий векторы m_i (строки матрицы) и концентрации z_i
+    pub fn get(&self) -> ([[f32; 4]; 4], [f32; 4]){ (self.m, self.z) }
+    ///The method that returns the mode of the distribution
+    ///
+    ///Метод, возвращающий моду распределения
+    pub fn mode(&self) -> UnitQNum{
+        let v = self.m[0];
+        UnitQNum::make(QNum::make_from_r(v[0], v[1], v[2], v[3]))
+    }
+    fn exponent(&self, q:&UnitQNum) -> f64{
+        let (r, i, j, k) = q.get();
+        let mut s = 0_f64;
+        for (m, z) in self.m.iter().zip(self.z) {
+            let d = (m[0]*r + m[1]*i + m[2]*j + m[3]*k) as f64;
+            s += z as f64*d*d;
+        }
+        s
+    }
+    ///The method that returns the probability density at the unit quaternion 'q'
+    ///
+    ///Метод, возвращающий плотность вероятности в единичном кватернионе 'q'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::Bingham;
+    /// use tmn::quaternion::UnitQNum;
+    /// let m = [[1_f32, 0_f32, 0_f32, 0_f32], [0_f32, 1_f32, 0_f32, 0_f32], [0_f32, 0_f32, 1_f32, 0_f32], [0_f32, 0_f32, 0_f32, 1_f32]];
+    /// //Равномерное распределение
+    /// let d = Bingham::make(m, [0_f32; 4]);
+    /// assert!((d.pdf(UnitQNum::make_identity())-1_f32/(2_f32*std::f32::consts::PI.powf(2_f32))).abs() < 0.000001);
+    /// //Плотность одинакова для q и -q
+    /// let d = Bingham::make(m, [0_f32, -1_f32, -4_f32, -9_f32]);
+    /// let q = UnitQNum::make_from_a(1_f32, (1_f32, 2_f32, 3_f32));
+    /// assert_eq!(d.pdf(q.clone()), d.pdf(-q));
+    /// ```
+    pub fn pdf(&self, q:UnitQNum) -> f32{
+        (self.exponent(&q).exp()/self.norm) as f32
+    }
+    ///The method that returns a random unit quaternion (rejection sampling with the angular central Gaussian envelope by Kent, Ganeiber and Mardia)
+    ///
+    ///Метод, возвращающий случайный единичный кватернион (выборка с отклонением с угловым центральным гауссовским распределением в качестве огибающей по Кенту, Ганейберу и Мардиа)
+    pub fn sample(&self, rng:&mut Rng) -> UnitQNum{
+        let a = self.z.map(|z| -z as f64);
+        //Параметр огибающей b - корень sum(1/(b + 2·a_i)) = 1
+        let (mut lo, mut hi) = (1e-12_f64, 4_f64);
+        for _ in 0..100 {
+            let b = (lo + hi)/2_f64;
+            let s: f64 = a.iter().map(|a| 1_f64/(b + 2_f64*a)).sum();
+            if s > 1_f64 { lo = b } else { hi = b }
+        }
+        let b = (lo + hi)/2_f64;
+        let omega = a.map(|a| 1_f64 + 2_f64*a/b);
+        let log_m = -(4_f64 - b)/2_f64 + 2_f64*(4_f64/b).ln();
+        loop {
+            let y = omega.map(|o| rng.gauss() as f64/o.powf(0.5));
+            let n = y.iter().map(|v| v*v).sum::<f64>().powf(0.5);
+            if n == 0_f64 {
+                continue;
+            }
+            let x = y.map(|v| v/n);
+            let xax: f64 = x.iter().zip(a).map(|(x, a)| a*x*x).sum();
+            let xox: f64 = x.iter().zip(omega).map(|(x, o)| o*x*x).sum();
+            let u = (1_f32 - rng.next_f32()) as f64;
+            if u.ln() < -xax + 2_f64*xox.ln() - log_m {
+                //Переход из собственного базиса
+                let mut q = [0_f32; 4];
+                for (m, x) in self.m.iter().zip(x) {
+                    for c in 0..4 {
+                        q[c] += m[c]*x as f32;
+                    }
+                }
+                return UnitQNum::make(QNum::make_from_r(q[0], q[1], q[2], q[3]));
+            }
+        }
+    }
+    ///The function that returns the maximum likelihood estimation of the distribution from the unit quaternions 'qs'. The vectors m_i are the eigenvectors of the scatter matrix, the concentrations are found by Newton's method so that the second moments of the distribution equal its eigenvalues. Concentrations up to about a thousand are supported
+    ///
+    ///Функция, возвращающая оценку распределения по методу максимального правдоподобия по единичным кватернионам 'qs'. Векторы m_i являются собственными векторами матрицы рассеяния, концентрации находятся методом Ньютона так, чтобы вторые моменты распределения были равны ее собственным значениям. Поддерживаются концентрации примерно до тысячи
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::Bingham;
+    /// use tmn::quaternion::UnitQNum;
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(12);
+    /// let q = UnitQNum::make_from_a(0.5_f32, (0_f32, 0_f32, 1_f32));
+    /// let (r, i, j, k) = q.get();
+    /// let m = [[r, i, j, k], [-i, r, -k, j], [-j, k, r, -i], [-k, -j, i, r]];
+    /// let d = Bingham::make(m, [0_f32, -5_f32, -10_f32, -20_f32]);
+    /// let qs: Vec<_> = (0..5000).map(|_| d.sample(&mut rng)).collect();
+    /// let f = Bingham::fit(&qs);
+    /// let (_, z) = f.get();
+    /// assert!(f.mode().get_q().dot(q.get_q()).abs() > 0.999_f32);
+    /// assert!((z[1]+5_f32).abs() < 0.5 && (z[2]+10_f32).abs() < 1_f32 && (z[3]+20_f32).abs() < 2_f32);
+    /// ```
+    pub fn fit(qs:&[UnitQNum]) -> Bingham{
+        assert!(!qs.is_empty());
+        let mut s = [[0_f32; 4]; 4];
+        for q in qs {
+            let (r, i, j, k) = q.get();
+            let v = [r, i, j, k];
+            for (row, a) in s.iter_mut().zip(v) {
+                for (e, b) in row.iter_mut().zip(v) {
+                    *e += a*b/qs.len() as f32;
+                }
+            }
+        }
+        let (vals, vecs) = linalg::sym_eigen(&s);
+        let target = vals.map(|v| (v as f64).max(1e-6_f64));
+        let moments = |z:&[f64; 3]| -> [f64; 3]{
+            let (f, m) = bingham_integrals([0_f64, z[0], z[1], z[2]]);
+            [m[1]/f, m[2]/f, m[3]/f]
+        };
+        //Начальное приближение для сильно сконцентрированного распределения
+        let mut z = [1, 2, 3].map(|i| (-1_f64/(2_f64*target[i]) + 1_f64/(2_f64*target[0])).min(0_f64));
+        for _ in 0..50 {
+            let e = moments(&z);
+            let res = [e[0] - target[1], e[1] - target[2], e[2] - target[3]];
+            if res.iter().all(|r| r.abs() < 1e-7_f64) {
+                break;
+            }
+            //Якобиан конечными разностями
+            let mut jac = [[0_f64; 3]; 3];
+            for c in 0..3 {
+                let h = 1e-4_f64*(1_f64 + z[c].abs());
+                let mut zh = z;
+                zh[c] -= h;
+                let eh = moments(&zh);
+                for r in 0..3 {
+                    jac[r][c] = (e[r] - eh[r])/h;
+                }
+            }
+            let step = match solve3(&jac, res) {
+                Some(step) => step,
+                None => break
+            };
+            //Шаг ограничивается, чтобы концентрации оставались неположительными
+            let mut t = 1_f64;
+            while (0..3).any(|i| z[i] - t*step[i] > 0_f64) && t > 1e-3_f64 {
+                t /= 2_f64;
+            }
+            for i in 0..3 {
+                z[i] = (z[i] - t*step[i]).min(0_f64);
+            }
+        }
+        Bingham::make(vecs, [0_f32, z[0] as f32, z[1] as f32, z[2] as f32])
+    }
+}
+
+///Matrix Fisher distribution on rotations with the density exp(tr(F^T·R)) with respect to the normalized Haar measure on SO(3). It is equivalent to the Bingham distribution of the corresponding quaternions
+///
+///Матричное распределение Фишера на поворотах с плотностью exp(tr(F^T·R)) относительно нормированной меры Хаара на SO(3). Оно эквивалентно распределению Бингама соответствующих кватернионов
+pub struct MatrixFisher{
+    f:[[f32; 3]; 3],
+    bingham:Bingham
+}
+
+impl MatrixFisher {
+    //Матрица 4x4 квадратичной формы q^T·B·q = tr(F^T·R(q))
+    fn to_quadratic(f:&[[f32; 3]; 3]) -> [[f32; 4]; 4]{
+        let brr = f[0][0] + f[1][1] + f[2][2];
+        let bii = f[0][0] - f[1][1] - f[2][2];
+        let bjj = -f[0][0] + f[1][1] - f[2][2];
+        let bkk = -f[0][0] - f[1][1] + f[2][2];
+        let bri = f[2][1] - f[1][2];
+        let brj = f[0][2] - f[2][0];
+        let brk = f[1][0] - f[0][1];
+        let bij = f[0][1] + f[1][0];
+        let bik = f[0][2] + f[2][0];
+        let bjk = f[1][2] + f[2][1];
+        [
+            [brr, bri, brj, brk],
+            [bri, bii, bij, bik],
+            [brj, bij, bjj, bjk],
+            [brk, bik, bjk, bkk]
+        ]
+    }
+    fn from_quadratic(b:&[[f32; 4]; 4]) -> [[f32; 3]; 3]{
+        //Квадратичная форма на единичной сфере определена с точностью до b + c·I
+        let t = (b[0][0] + b[1][1] + b[2][2] + b[3][3])/4_f32;
+        let d = [b[0][0] - t, b[1][1] - t, b[2][2] - t, b[3][3] - t];
+        [
+            [(d[0] + d[1])/2_f32, (b[1][2] - b[0][3])/2_f32, (b[0][2] + b[1][3])/2_f32],
+            [(b[0][3] + b[1][2])/2_f32, (d[0] + d[2])/2_f32, (b[2][3] - b[0][1])/2_f32],
+            [(b[1][3] - b[0][2])/2_f32, (b[0][1] + b[2][3])/2_f32, (d[0] + d[3])/2_f32]
+        ]
+    }
+    ///The function for creating the distribution from the parameter matrix 'f' (row by row)
+    ///
+    ///Функция для создания распределения из матрицы параметров 'f' (по строкам)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::MatrixFisher;
+    /// let f = [[1_f32, 2_f32, 0_f32], [0_f32, 3_f32, 0_f32], [0_f32, -1_f32, 2_f32]];
+    /// assert_eq!(f, MatrixFisher::make(f).get());
+    /// ```
+    pub fn make(f:[[f32; 3]; 3]) -> Self{
+        let bingham = Bingham::make_from_matrix(&MatrixFisher::to_quadratic(&f));
+        MatrixFisher{f, bingham}
+    }
+    ///The method that returns the parameter matrix
+    ///
+    ///Метод, возвращающий матрицу параметров
+    pub fn get(&self) -> [[f32; 3]; 3]{ self.f }
+    ///The method that returns the mode of the distribution
+    ///
+    ///Метод, возвращающий моду распределения
+    pub fn mode(&self) -> UnitQNum{ self.bingham.mode() }
+    ///The method that returns the probability density at the rotation 'q' with respect to the normalized Haar measure
+    ///
+    ///Метод, возвращающий плотность вероятности в повороте 'q' относительно нормированной меры Хаара
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::MatrixFisher;
+    /// use tmn::quaternion::UnitQNum;
+    /// use tmn::random::Rng;
+    /// let d = MatrixFisher::make([[2_f32, 0_f32, 0_f32], [0_f32, 1_f32, 0_f32], [0_f32, 0_f32, 0.5_f32]]);
+    /// //Среднее значение плотности по равномерному распределению поворотов равно единице
+    /// let mut rng = Rng::make(13);
+    /// let mean = (0..20000).map(|_| d.pdf(rng.unit_quaternion())).sum::<f32>()/20000_f32;
+    /// assert!((mean-1_f32).abs() < 0.03);
+    /// ```
+    pub fn pdf(&self, q:UnitQNum) -> f32{
+        //Поверхность S3 дважды накрывает SO(3), нормированная мера Хаара соответствует площади 2·PI²
+        (self.bingham.pdf(q) as f64*2_f64*PI*PI) as f32
+    }
+    ///The method that returns a random rotation
+    ///
+    ///Метод, возвращающий случайный поворот
+    pub fn sample(&self, rng:&mut Rng) -> UnitQNum{ self.bingham.sample(rng) }
+    ///The function that returns the maximum likelihood estimation of the distribution from the rotations 'qs'
+    ///
+    ///Функция, возвращающая оценку распределения по методу максимального правдоподобия по поворотам 'qs'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::directional::MatrixFisher;
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(14);
+    /// let f = [[6_f32, 1_f32, 0_f32], [0_f32, 4_f32, 0_f32], [0_f32, 0_f32, 3_f32]];
+    /// let d = MatrixFisher::make(f);
+    /// let qs: Vec<_> = (0..5000).map(|_| d.sample(&mut rng)).collect();
+    /// let e = MatrixFisher::fit(&qs).get();
+    /// for r in 0..3 {
+    ///     for c in 0..3 {
+    ///         assert!((e[r][c]-f[r][c]).abs() < 0.6);
+    ///     }
+    /// }
+    /// ```
+    pub fn fit(qs:&[UnitQNum]) -> MatrixFisher{
+        let bingham = Bingham::fit(qs);
+        let (m, z) = bingham.get();
+        let mut b = [[0_f32; 4]; 4];
+        for (v, z) in m.iter().zip(z) {
+            for r in 0..4 {
+                for c in 0..4 {
+                    b[r][c] += z*v[r]*v[c];
+                }
+            }
+        }
+        MatrixFisher::make(MatrixFisher::from_quadratic(&b))
+    }
+}
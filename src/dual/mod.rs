@@ -0,0 +1,299 @@
+//!Dual numbers for forward-mode automatic differentiation
+//!
+//!Дуальные числа для автоматического дифференцирования в прямом режиме
+use std::ops::Neg;
+use crate::complex::CNum;
+
+///The structure storing the dual number r + εd, where ε² = 0. If the variable x is given as x + ε, then the result of any function f calculated with dual arithmetic is f(x) + εf'(x)
+///
+///Структура, хранящая дуальное число r + εd, где ε² = 0. Если переменная x задана как x + ε, то результат любой функции f, вычисленной в дуальной арифметике, равен f(x) + εf'(x)
+pub struct DualNum{r:f32, d:f32}
+
+impl DualNum {
+    ///The function that creates a dual number from the real part 'r' and the dual part 'd'
+    ///
+    ///Функция, создающая дуальное число из действительной части 'r' и дуальной части 'd'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// let a = DualNum::make(2_f32, 3_f32);
+    /// assert_eq!((2_f32, 3_f32), a.get());
+    /// ```
+    pub fn make(r:f32, d:f32) -> Self{ DualNum{r, d} }
+    ///The function that creates the independent variable x + ε
+    ///
+    ///Функция, создающая независимую переменную x + ε
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// //f(x) = x³·sin(x), f'(x) = 3x²·sin(x) + x³·cos(x)
+    /// let x = DualNum::make_var(1.5_f32);
+    /// let (f, df) = x.mult_d(x.clone()).mult_d(x.clone()).mult_d(x.sin()).get();
+    /// assert!((f-1.5_f32.powi(3)*1.5_f32.sin()).abs() < 0.00001);
+    /// assert!((df-(3_f32*1.5_f32.powi(2)*1.5_f32.sin() + 1.5_f32.powi(3)*1.5_f32.cos())).abs() < 0.00001);
+    /// ```
+    pub fn make_var(x:f32) -> Self{ DualNum{r:x, d:1_f32} }
+    ///The function that creates the constant x + 0ε
+    ///
+    ///Функция, создающая константу x + 0ε
+    pub fn make_const(x:f32) -> Self{ DualNum{r:x, d:0_f32} }
+    ///The method for cloning a dual number
+    ///
+    ///Метод для клонирования дуального числа
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> DualNum{ DualNum{r:self.r, d:self.d} }
+    ///The method that returns the real and the dual parts (the value and the derivative)
+    ///
+    ///Метод, возвращающий действительную и дуальную части (значение и производную)
+    pub fn get(&self) -> (f32, f32){ (self.r, self.d) }
+    ///The method that returns the sum of a dual and a real number
+    ///
+    ///Метод, возвращающий сумму дуального и действительного числа
+    pub fn add_r(&self, v:f32) -> DualNum{ DualNum{r:self.r + v, d:self.d} }
+    ///The method that returns the sum of dual numbers
+    ///
+    ///Метод, возвращающий сумму дуальных чисел
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// let a = DualNum::make(2_f32, 3_f32).add_d(DualNum::make(1_f32, -1_f32));
+    /// assert_eq!((3_f32, 2_f32), a.get());
+    /// ```
+    pub fn add_d(&self, v:DualNum) -> DualNum{ DualNum{r:self.r + v.r, d:self.d + v.d} }
+    ///The method that returns the product of a dual and a real number
+    ///
+    ///Метод, возвращающий произведение дуального и действительного числа
+    pub fn mult_r(&self, v:f32) -> DualNum{ DualNum{r:self.r*v, d:self.d*v} }
+    ///The method that returns the product of dual numbers: (a + εb)(c + εd) = ac + ε(ad + bc)
+    ///
+    ///Метод, возвращающий произведение дуальных чисел: (a + εb)(c + εd) = ac + ε(ad + bc)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// let a = DualNum::make(2_f32, 3_f32).mult_d(DualNum::make(4_f32, 5_f32));
+    /// assert_eq!((8_f32, 22_f32), a.get());
+    /// //ε² = 0
+    /// assert_eq!((0_f32, 0_f32), DualNum::make(0_f32, 1_f32).mult_d(DualNum::make(0_f32, 1_f32)).get());
+    /// ```
+    pub fn mult_d(&self, v:DualNum) -> DualNum{ DualNum{r:self.r*v.r, d:self.r*v.d + self.d*v.r} }
+    ///The method that returns the inverse dual number (the real part must not be zero)
+    ///
+    ///Метод, возвращающий обратное дуальное число (действительная часть не должна быть равна нулю)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// //(1/x)' = -1/x²
+    /// assert_eq!((0.5_f32, -0.25_f32), DualNum::make_var(2_f32).inv().get());
+    /// ```
+    pub fn inv(&self) -> DualNum{ DualNum{r:1_f32/self.r, d:-self.d/(self.r*self.r)} }
+    ///The method for dividing dual numbers
+    ///
+    ///Метод для деления дуальных чисел
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// //(x/(x+1))' = 1/(x+1)²
+    /// let x = DualNum::make_var(1_f32);
+    /// assert_eq!((0.5_f32, 0.25_f32), x.div_d(x.add_r(1_f32)).get());
+    /// ```
+    pub fn div_d(&self, v:DualNum) -> DualNum{ self.mult_d(v.inv()) }
+    ///The method that returns the dual number raised to the real power 'v'
+    ///
+    ///Метод, возвращающий дуальное число, возведенное в действительную степень 'v'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// let (f, df) = DualNum::make_var(4_f32).powf(1.5_f32).get();
+    /// assert!((f-8_f32).abs() < 0.00001 && (df-3_f32).abs() < 0.00001);
+    /// ```
+    pub fn powf(&self, v:f32) -> DualNum{ DualNum{r:self.r.powf(v), d:v*self.r.powf(v - 1_f32)*self.d} }
+    ///The method that returns the square root
+    ///
+    ///Метод, возвращающий квадратный корень
+    pub fn sqrt(&self) -> DualNum{
+        let s = self.r.sqrt();
+        DualNum{r:s, d:self.d/(2_f32*s)}
+    }
+    ///The method that returns the sine
+    ///
+    ///Метод, возвращающий синус
+    pub fn sin(&self) -> DualNum{ DualNum{r:self.r.sin(), d:self.r.cos()*self.d} }
+    ///The method that returns the cosine
+    ///
+    ///Метод, возвращающий косинус
+    pub fn cos(&self) -> DualNum{ DualNum{r:self.r.cos(), d:-self.r.sin()*self.d} }
+    ///The method that returns the exponent
+    ///
+    ///Метод, возвращающий экспоненту
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// //(exp(x²))' = 2x·exp(x²)
+    /// let x = DualNum::make_var(0.5_f32);
+    /// let (f, df) = x.mult_d(x.clone()).exp().get();
+    /// assert!((f-0.25_f32.exp()).abs() < 0.00001 && (df-0.25_f32.exp()).abs() < 0.00001);
+    /// ```
+    pub fn exp(&self) -> DualNum{
+        let e = self.r.exp();
+        DualNum{r:e, d:e*self.d}
+    }
+    ///The method that returns the natural logarithm
+    ///
+    ///Метод, возвращающий натуральный логарифм
+    pub fn ln(&self) -> DualNum{ DualNum{r:self.r.ln(), d:self.d/self.r} }
+    ///The function that returns the angle of the point (x, y), like 'f32::atan2'
+    ///
+    ///Функция, возвращающая угол точки (x, y), как 'f32::atan2'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// //d/dt atan2(sin t, cos t) = 1
+    /// let t = DualNum::make_var(2_f32);
+    /// let (f, df) = DualNum::atan2(t.sin(), t.cos()).get();
+    /// assert!((f-2_f32).abs() < 0.00001 && (df-1_f32).abs() < 0.00001);
+    /// ```
+    pub fn atan2(y:DualNum, x:DualNum) -> DualNum{
+        DualNum{r:y.r.atan2(x.r), d:(x.r*y.d - y.r*x.d)/(x.r*x.r + y.r*y.r)}
+    }
+}
+
+impl PartialEq for DualNum{
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+impl Neg for DualNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::DualNum;
+    /// assert_eq!((-2_f32, 3_f32), (-DualNum::make(2_f32, -3_f32)).get());
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1_f32)
+    }
+}
+
+///The structure storing the dual-valued complex number r + εd, where r and d are complex numbers
+///
+///Структура, хранящая дуальнозначное комплексное число r + εd, где r и d - комплексные числа
+pub struct DualCNum{r:CNum, d:CNum}
+
+impl DualCNum {
+    ///The function that creates a dual-valued complex number from the real part 'r' and the dual part 'd'
+    ///
+    ///Функция, создающая дуальнозначное комплексное число из действительной части 'r' и дуальной части 'd'
+    pub fn make(r:CNum, d:CNum) -> Self{ DualCNum{r, d} }
+    ///The function that creates a dual-valued complex number from the dual real and imaginary parts
+    ///
+    ///Функция, создающая дуальнозначное комплексное число из дуальных действительной и мнимой частей
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::{DualNum, DualCNum};
+    /// let a = DualCNum::make_from_d(DualNum::make(1_f32, 2_f32), DualNum::make(3_f32, 4_f32));
+    /// let (r, d) = a.get();
+    /// assert_eq!(((1_f32, 3_f32), (2_f32, 4_f32)), (r.get(), d.get()));
+    /// ```
+    pub fn make_from_d(re:DualNum, im:DualNum) -> Self{
+        DualCNum{r:CNum::make(re.r, im.r), d:CNum::make(re.d, im.d)}
+    }
+    ///The method for cloning a dual-valued complex number
+    ///
+    ///Метод для клонирования дуальнозначного комплексного числа
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> DualCNum{ DualCNum{r:self.r.clone(), d:self.d.clone()} }
+    ///The method that returns the real and the dual parts (the value and the derivative)
+    ///
+    ///Метод, возвращающий действительную и дуальную части (значение и производную)
+    pub fn get(&self) -> (CNum, CNum){ (self.r.clone(), self.d.clone()) }
+    ///The method that returns the dual real and imaginary parts
+    ///
+    ///Метод, возвращающий дуальные действительную и мнимую части
+    pub fn get_d(&self) -> (DualNum, DualNum){
+        let ((r0, i0), (r1, i1)) = (self.r.get(), self.d.get());
+        (DualNum::make(r0, r1), DualNum::make(i0, i1))
+    }
+    ///The method that returns the conjugate number
+    ///
+    ///Метод, возвращающий сопряженное число
+    pub fn conj(&self) -> DualCNum{ DualCNum{r:self.r.conj(), d:self.d.conj()} }
+    ///The method that returns the sum of dual-valued complex numbers
+    ///
+    ///Метод, возвращающий сумму дуальнозначных комплексных чисел
+    pub fn add_c(&self, v:DualCNum) -> DualCNum{ DualCNum{r:self.r.add_c(v.r), d:self.d.add_c(v.d)} }
+    ///The method that returns the product of a dual-valued complex number and a real number
+    ///
+    ///Метод, возвращающий произведение дуальнозначного комплексного числа и действительного числа
+    pub fn mult_r(&self, v:f32) -> DualCNum{ DualCNum{r:self.r.mult_r(v), d:self.d.mult_r(v)} }
+    ///The method that returns the product of a dual-valued complex number and a dual number
+    ///
+    ///Метод, возвращающий произведение дуальнозначного комплексного числа и дуального числа
+    pub fn mult_d(&self, v:DualNum) -> DualCNum{
+        DualCNum{r:self.r.mult_r(v.r), d:self.r.mult_r(v.d).add_c(self.d.mult_r(v.r))}
+    }
+    ///The method that returns the product of dual-valued complex numbers
+    ///
+    ///Метод, возвращающий произведение дуальнозначных комплексных чисел
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::{DualNum, DualCNum};
+    /// use tmn::complex::CNum;
+    /// //d/dt (e^(it))³ = 3i·e^(3it)
+    /// let t = DualNum::make_var(0.4_f32);
+    /// let z = DualCNum::make_from_d(t.cos(), t.sin());
+    /// let (f, df) = z.mult_c(z.clone()).mult_c(z).get();
+    /// let e = CNum::make((1.2_f32).cos(), (1.2_f32).sin());
+    /// let de = e.mult_c(CNum::make(0_f32, 3_f32));
+    /// let ((a, b), (c, d)) = (f.add_c(-e).get(), df.add_c(-de).get());
+    /// assert!(a.abs() < 0.00001 && b.abs() < 0.00001 && c.abs() < 0.00001 && d.abs() < 0.00001);
+    /// ```
+    pub fn mult_c(&self, v:DualCNum) -> DualCNum{
+        DualCNum{r:self.r.mult_c(v.r.clone()), d:self.r.mult_c(v.d).add_c(self.d.mult_c(v.r))}
+    }
+    ///The method that returns the squared module as a dual number
+    ///
+    ///Метод, возвращающий квадрат модуля в виде дуального числа
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual::{DualNum, DualCNum};
+    /// //|t + 2ti|² = 5t², производная 10t
+    /// let t = DualNum::make_var(3_f32);
+    /// let z = DualCNum::make_from_d(t.clone(), t.mult_r(2_f32));
+    /// assert_eq!((45_f32, 30_f32), z.norm().get());
+    /// ```
+    pub fn norm(&self) -> DualNum{
+        let (re, im) = self.get_d();
+        re.mult_d(re.clone()).add_d(im.mult_d(im.clone()))
+    }
+}
+
+impl PartialEq for DualCNum{
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.d == other.d
+    }
+}
+impl Neg for DualCNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1_f32)
+    }
+}
@@ -0,0 +1,419 @@
+//!Dual quaternions
+use std::ops::Neg;
+use crate::linalg;
+use crate::quaternion::{QNum, UnitQNum};
+use crate::dual::DualNum;
+
+///The structure storing the dual quaternion r + εd, where ε² = 0. A unit dual quaternion describes a rigid-body transform: a rotation followed by a translation
+///
+/// Структура, хранящая дуальный кватернион r + εd, где ε² = 0. Единичный дуальный кватернион описывает движение твердого тела: поворот, за которым следует перенос
+///
+///The same structure is the dual-valued quaternion for automatic differentiation (see 'tmn::dual'): d is the derivative of r
+///
+/// Эта же структура является дуальнозначным кватернионом для автоматического дифференцирования (см. 'tmn::dual'): d - производная r
+pub struct DualQNum{r:QNum, d:QNum}
+
+impl DualQNum {
+    ///The function that creates a dual quaternion from the real part 'r' and the dual part 'd'
+    ///
+    ///Функция, создающая дуальный кватернион из действительной части 'r' и дуальной части 'd'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = DualQNum::make(QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32), QNum::make_from_r(0_f32, 1_f32, 2_f32, 3_f32));
+    /// let (r, d) = a.get();
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), r.get());
+    /// assert_eq!((0_f32, 1_f32, 2_f32, 3_f32), d.get());
+    /// ```
+    pub fn make(r:QNum, d:QNum) -> Self{ DualQNum{r, d} }
+    ///The function for creating the identity transform
+    ///
+    ///Функция для создания тождественного преобразования
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// let a = DualQNum::make_identity();
+    /// assert_eq!((1_f32, 2_f32, 3_f32), a.transform_point((1_f32, 2_f32, 3_f32)));
+    /// ```
+    pub fn make_identity() -> Self{
+        DualQNum{r:QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32), d:QNum::make_zero()}
+    }
+    ///The function that creates the transform from the rotation 'rot' followed by the translation 't'
+    ///
+    ///Функция, создающая преобразование из поворота 'rot', за которым следует перенос 't'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_from_rt(UnitQNum::make_from_a(0.5_f32, (1_f32, 1_f32, 0_f32)), (1_f32, -2_f32, 3_f32));
+    /// let (x, y, z) = a.get_translation();
+    /// assert!((x-1_f32).abs() < 0.000001 && (y+2_f32).abs() < 0.000001 && (z-3_f32).abs() < 0.000001);
+    /// let (ang, _) = a.get_rotation().to_axis_angle();
+    /// assert!((ang-0.5_f32).abs() < 0.000001);
+    /// ```
+    pub fn make_from_rt(rot:UnitQNum, t:(f32, f32, f32)) -> Self{
+        let r = rot.get_q();
+        let d = QNum::make_from_r(0_f32, t.0, t.1, t.2).mult_q(r.clone()).mult_r(0.5_f32);
+        DualQNum{r, d}
+    }
+    ///The function that creates a dual-valued quaternion from 4 dual coefficients
+    ///
+    ///Функция, создающая дуальнозначный кватернион из 4 дуальных коэффициентов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::dual::DualNum;
+    /// let a = DualQNum::make_from_d(DualNum::make(1_f32, 2_f32), DualNum::make(3_f32, 4_f32), DualNum::make(5_f32, 6_f32), DualNum::make(7_f32, 8_f32));
+    /// let (r, d) = a.get();
+    /// assert_eq!((1_f32, 3_f32, 5_f32, 7_f32), r.get());
+    /// assert_eq!((2_f32, 4_f32, 6_f32, 8_f32), d.get());
+    /// assert!(a.get_d().3 == DualNum::make(7_f32, 8_f32));
+    /// ```
+    pub fn make_from_d(r:DualNum, i:DualNum, j:DualNum, k:DualNum) -> Self{
+        let ((r0, r1), (i0, i1), (j0, j1), (k0, k1)) = (r.get(), i.get(), j.get(), k.get());
+        DualQNum{r:QNum::make_from_r(r0, i0, j0, k0), d:QNum::make_from_r(r1, i1, j1, k1)}
+    }
+    ///The function that creates a dual-valued rotation quaternion from the dual angle 'ang' and the dual axis 'vec', as 'QNum::make_from_a' does. The dual part is the exact derivative of the rotation quaternion
+    ///
+    ///Функция, создающая дуальнозначный кватернион поворота из дуального угла 'ang' и дуальной оси 'vec', как это делает 'QNum::make_from_a'. Дуальная часть - точная производная кватерниона поворота
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::dual::DualNum;
+    /// use tmn::quaternion::QNum;
+    /// //Производная по углу: dq/dθ = q·(0, axis)/2
+    /// let c = |x:f32| DualNum::make_const(x);
+    /// let q = DualQNum::make_from_a_d(DualNum::make_var(0.8_f32), (c(1_f32), c(2_f32), c(2_f32)));
+    /// let (r, d) = q.get();
+    /// let e = r.mult_q(QNum::make_from_r(0_f32, 1_f32/3_f32, 2_f32/3_f32, 2_f32/3_f32)).mult_r(0.5_f32);
+    /// let (a, b, g, h) = d.add_q(-e).get();
+    /// assert!(a.abs() < 0.000001 && b.abs() < 0.000001 && g.abs() < 0.000001 && h.abs() < 0.000001);
+    /// //Произведение кватернионов дифференцируется через mult_d
+    /// let t = DualNum::make_var(0.3_f32);
+    /// let a = DualQNum::make_from_a_d(t.clone(), (c(0_f32), c(0_f32), c(1_f32)));
+    /// let b = DualQNum::make_from_a_d(t.mult_r(2_f32), (c(0_f32), c(0_f32), c(1_f32)));
+    /// //Повороты вокруг одной оси складываются: производная суммарного угла равна 3
+    /// let (r, d) = a.mult_d(b).get();
+    /// let e = r.mult_q(QNum::make_from_r(0_f32, 0_f32, 0_f32, 1.5_f32));
+    /// let (a, b, g, h) = d.add_q(-e).get();
+    /// assert!(a.abs() < 0.000001 && b.abs() < 0.000001 && g.abs() < 0.000001 && h.abs() < 0.000001);
+    /// ```
+    pub fn make_from_a_d(ang:DualNum, vec:(DualNum, DualNum, DualNum)) -> Self{
+        let (x, y, z) = vec;
+        let m = x.mult_d(x.clone()).add_d(y.mult_d(y.clone())).add_d(z.mult_d(z.clone()));
+        if m.get().0 == 0_f32 {
+            return DualQNum::make_identity();
+        }
+        let half = ang.mult_r(0.5_f32);
+        let s = half.sin().div_d(m.sqrt());
+        DualQNum::make_from_d(half.cos(), x.mult_d(s.clone()), y.mult_d(s.clone()), z.mult_d(s))
+    }
+    ///The method for cloning a dual quaternion
+    ///
+    ///Метод для клонирования дуального кватерниона
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> DualQNum{ DualQNum{r:self.r.clone(), d:self.d.clone()} }
+    ///The method that returns the real and the dual parts
+    ///
+    ///Метод, возвращающий действительную и дуальную части
+    pub fn get(&self) -> (QNum, QNum){ (self.r.clone(), self.d.clone()) }
+    ///The method that returns the rotation of the transform
+    ///
+    ///Метод, возвращающий поворот преобразования
+    pub fn get_rotation(&self) -> UnitQNum{ UnitQNum::make(self.r.clone()) }
+    ///The method that returns the translation of the transform
+    ///
+    ///Метод, возвращающий перенос преобразования
+    pub fn get_translation(&self) -> (f32, f32, f32){
+        let (_, i, j, k) = self.d.mult_q(self.r.conj()).mult_r(2_f32/self.r.norm()).get();
+        (i, j, k)
+    }
+    ///The method that returns 4 dual coefficients of the dual-valued quaternion
+    ///
+    ///Метод, возвращающий 4 дуальных коэффициента дуальнозначного кватерниона
+    pub fn get_d(&self) -> (DualNum, DualNum, DualNum, DualNum){
+        let ((r0, i0, j0, k0), (r1, i1, j1, k1)) = (self.r.get(), self.d.get());
+        (DualNum::make(r0, r1), DualNum::make(i0, i1), DualNum::make(j0, j1), DualNum::make(k0, k1))
+    }
+    ///The method that rotates the dual vector 'v' by the dual-valued unit quaternion: q·v·q*. The dual part of the result is the derivative of the rotated vector, so the columns of rotation Jacobians are obtained exactly
+    ///
+    ///Метод, поворачивающий дуальный вектор 'v' дуальнозначным единичным кватернионом: q·v·q*. Дуальная часть результата - производная повернутого вектора, поэтому столбцы якобианов поворота получаются точно
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::dual::DualNum;
+    /// let c = |x:f32| DualNum::make_const(x);
+    /// //d/dθ R_z(θ)·(1, 0, 0) = (-sin θ, cos θ, 0)
+    /// let q = DualQNum::make_from_a_d(DualNum::make_var(0.6_f32), (c(0_f32), c(0_f32), c(1_f32)));
+    /// let (x, y, z) = q.rotate_vector_d((c(1_f32), c(0_f32), c(0_f32)));
+    /// let ((_, dx), (_, dy), (_, dz)) = (x.get(), y.get(), z.get());
+    /// assert!((dx+0.6_f32.sin()).abs() < 0.000001 && (dy-0.6_f32.cos()).abs() < 0.000001 && dz.abs() < 0.000001);
+    /// ```
+    pub fn rotate_vector_d(&self, v:(DualNum, DualNum, DualNum)) -> (DualNum, DualNum, DualNum){
+        let p = DualQNum::make_from_d(DualNum::make_const(0_f32), v.0, v.1, v.2);
+        let (_, i, j, k) = self.mult_d(p).mult_d(self.conj()).get_d();
+        (i, j, k)
+    }
+    ///The method that returns the sum of dual quaternions
+    ///
+    ///Метод, возвращающий сумму дуальных кватернионов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = DualQNum::make(QNum::make_from_r(1_f32, 1_f32, 1_f32, 1_f32), QNum::make_from_r(2_f32, 2_f32, 2_f32, 2_f32));
+    /// let (r, d) = a.add_d(a.clone()).get();
+    /// assert_eq!((2_f32, 2_f32, 2_f32, 2_f32), r.get());
+    /// assert_eq!((4_f32, 4_f32, 4_f32, 4_f32), d.get());
+    /// ```
+    pub fn add_d(&self, v:DualQNum) -> DualQNum{ DualQNum{r:self.r.add_q(v.r), d:self.d.add_q(v.d)} }
+    ///The method that returns the product of a dual quaternion and a real number
+    ///
+    ///Метод, возвращающий произведение дуального кватерниона и действительного числа
+    pub fn mult_r(&self, v:f32) -> DualQNum{ DualQNum{r:self.r.mult_r(v), d:self.d.mult_r(v)} }
+    ///The method that returns the product of dual quaternions. For transforms it is the composition: the right transform is applied first
+    ///
+    ///Метод, возвращающий произведение дуальных кватернионов. Для преобразований это композиция: правое преобразование применяется первым
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_from_rt(UnitQNum::make_from_a(0.3_f32, (0_f32, 0_f32, 1_f32)), (1_f32, 0_f32, 0_f32));
+    /// let b = DualQNum::make_from_rt(UnitQNum::make_from_a(1.1_f32, (1_f32, 0_f32, 0_f32)), (0_f32, 2_f32, 0_f32));
+    /// let p = (0.5_f32, -1_f32, 2_f32);
+    /// let (x, y, z) = a.mult_d(b.clone()).transform_point(p);
+    /// let (x1, y1, z1) = a.transform_point(b.transform_point(p));
+    /// assert!((x-x1).abs() < 0.00001 && (y-y1).abs() < 0.00001 && (z-z1).abs() < 0.00001);
+    /// ```
+    pub fn mult_d(&self, v:DualQNum) -> DualQNum{
+        DualQNum{
+            r:self.r.mult_q(v.r.clone()),
+            d:self.r.mult_q(v.d).add_q(self.d.mult_q(v.r))
+        }
+    }
+    ///The method that returns the quaternion conjugate r* + εd*. For a unit dual quaternion it is the inverse transform
+    ///
+    ///Метод, возвращающий кватернионно сопряженное r* + εd*. Для единичного дуального кватерниона это обратное преобразование
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_from_rt(UnitQNum::make_from_a(0.7_f32, (1_f32, 2_f32, 0_f32)), (3_f32, 0_f32, -1_f32));
+    /// let (x, y, z) = a.conj().transform_point(a.transform_point((1_f32, 1_f32, 1_f32)));
+    /// assert!((x-1_f32).abs() < 0.00001 && (y-1_f32).abs() < 0.00001 && (z-1_f32).abs() < 0.00001);
+    /// ```
+    pub fn conj(&self) -> DualQNum{ DualQNum{r:self.r.conj(), d:self.d.conj()} }
+    ///The method that returns the dual conjugate r - εd
+    ///
+    ///Метод, возвращающий дуально сопряженное r - εd
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = DualQNum::make(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32), QNum::make_from_r(5_f32, 6_f32, 7_f32, 8_f32));
+    /// let (r, d) = a.dual_conj().get();
+    /// assert_eq!((1_f32, 2_f32, 3_f32, 4_f32), r.get());
+    /// assert_eq!((-5_f32, -6_f32, -7_f32, -8_f32), d.get());
+    /// ```
+    pub fn dual_conj(&self) -> DualQNum{ DualQNum{r:self.r.clone(), d:-self.d.clone()} }
+    ///The method that returns the full conjugate r* - εd*
+    ///
+    ///Метод, возвращающий полное сопряженное r* - εd*
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = DualQNum::make(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32), QNum::make_from_r(5_f32, 6_f32, 7_f32, 8_f32));
+    /// let (r, d) = a.full_conj().get();
+    /// assert_eq!((1_f32, -2_f32, -3_f32, -4_f32), r.get());
+    /// assert_eq!((-5_f32, 6_f32, 7_f32, 8_f32), d.get());
+    /// ```
+    pub fn full_conj(&self) -> DualQNum{ DualQNum{r:self.r.conj(), d:-self.d.conj()} }
+    ///The method that returns the unit dual quaternion: the real part is normalized and the dual part is made orthogonal to it. A dual quaternion with the zero real part is returned unchanged
+    ///
+    ///Метод, возвращающий единичный дуальный кватернион: действительная часть нормализуется, а дуальная делается ортогональной ей. Дуальный кватернион с нулевой действительной частью возвращается без изменений
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = DualQNum::make(QNum::make_from_r(2_f32, 0_f32, 0_f32, 0_f32), QNum::make_from_r(1_f32, 1_f32, 0_f32, 0_f32)).normalize();
+    /// let (r, d) = a.get();
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), r.get());
+    /// assert_eq!((0_f32, 0.5_f32, 0_f32, 0_f32), d.get());
+    /// ```
+    pub fn normalize(&self) -> DualQNum{
+        let n = self.r.modl();
+        if n == 0_f32 {
+            return self.clone();
+        }
+        let r = self.r.mult_r(1_f32/n);
+        let d = self.d.mult_r(1_f32/n);
+        let d = d.add_q(r.mult_r(-r.dot(d.clone())));
+        DualQNum{r, d}
+    }
+    ///The method that transforms the point 'p': rotates it and then translates
+    ///
+    ///Метод, преобразующий точку 'p': поворачивает ее, а затем переносит
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let rot = UnitQNum::make_from_a(0.9_f32, (1_f32, -1_f32, 2_f32));
+    /// let t = (4_f32, 5_f32, -6_f32);
+    /// let a = DualQNum::make_from_rt(rot.clone(), t);
+    /// //Сравнение с однородной матрицей
+    /// let mut m = rot.to_homogeneous();
+    /// (m[0][3], m[1][3], m[2][3]) = t;
+    /// let p = [1_f32, 2_f32, 3_f32, 1_f32];
+    /// let e: Vec<f32> = m.iter().map(|row| row.iter().zip(p).map(|(a, b)| a*b).sum()).collect();
+    /// let (x, y, z) = a.transform_point((1_f32, 2_f32, 3_f32));
+    /// assert!((x-e[0]).abs() < 0.00001 && (y-e[1]).abs() < 0.00001 && (z-e[2]).abs() < 0.00001);
+    /// ```
+    pub fn transform_point(&self, p:(f32, f32, f32)) -> (f32, f32, f32){
+        let p = UnitQNum::make(self.r.clone()).rotate_vector(p);
+        linalg::v_add(p, self.get_translation())
+    }
+    ///The method that returns the 4x4 homogeneous transformation matrix (row by row)
+    ///
+    ///Метод, возвращающий однородную матрицу преобразования 4x4 (по строкам)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_from_rt(UnitQNum::make_from_a(0.4_f32, (0_f32, 1_f32, 0_f32)), (1_f32, 2_f32, 3_f32));
+    /// let b = DualQNum::make_from_rt(UnitQNum::make_from_a(-1.2_f32, (1_f32, 0_f32, 1_f32)), (0_f32, -1_f32, 0_f32));
+    /// //Произведение дуальных кватернионов соответствует произведению матриц
+    /// let (ma, mb, mab) = (a.to_homogeneous(), b.to_homogeneous(), a.mult_d(b).to_homogeneous());
+    /// for r in 0..4 {
+    ///     for c in 0..4 {
+    ///         let v: f32 = (0..4).map(|n| ma[r][n]*mb[n][c]).sum();
+    ///         assert!((v-mab[r][c]).abs() < 0.00001);
+    ///     }
+    /// }
+    /// ```
+    pub fn to_homogeneous(&self) -> [[f32; 4]; 4]{
+        let mut m = self.r.to_homogeneous();
+        (m[0][3], m[1][3], m[2][3]) = self.get_translation();
+        m
+    }
+    ///The method that raises the unit dual quaternion to the power 's': the angle of rotation and the displacement along the screw axis are multiplied by 's'
+    ///
+    ///Метод, возводящий единичный дуальный кватернион в степень 's': угол поворота и смещение вдоль винтовой оси умножаются на 's'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_from_rt(UnitQNum::make_from_a(0.6_f32, (0_f32, 0_f32, 1_f32)), (1_f32, 0_f32, 0.4_f32));
+    /// let h = a.pow(0.5_f32);
+    /// let (x, y, z) = h.mult_d(h.clone()).transform_point((1_f32, 1_f32, 1_f32));
+    /// let (x1, y1, z1) = a.transform_point((1_f32, 1_f32, 1_f32));
+    /// assert!((x-x1).abs() < 0.00001 && (y-y1).abs() < 0.00001 && (z-z1).abs() < 0.00001);
+    /// ```
+    pub fn pow(&self, s:f32) -> DualQNum{
+        let a = self.normalize();
+        let t = a.get_translation();
+        let (ang, l) = a.r.to_axis_angle();
+        let half = (ang/2_f32).sin();
+        if half.abs() < 0.00001_f32 {
+            //Почти чистый перенос
+            let rot = UnitQNum::make(a.r.clone()).to_rotation_vector();
+            return DualQNum::make_from_rt(UnitQNum::from_rotation_vector(linalg::v_mult_r(rot, s)), linalg::v_mult_r(t, s));
+        }
+        //Параметры винта: смещение вдоль оси и момент оси
+        let dd = linalg::v_dot(t, l);
+        let cot = (ang/2_f32).cos()/half;
+        let perp = linalg::v_add(t, linalg::v_mult_r(l, -dd));
+        let m = linalg::v_mult_r(linalg::v_add(linalg::v_cross(t, l), linalg::v_mult_r(perp, cot)), 0.5_f32);
+        let (ang, dd) = (ang*s, dd*s);
+        let (sn, cs) = ((ang/2_f32).sin(), (ang/2_f32).cos());
+        let r = QNum::make_from_r(cs, sn*l.0, sn*l.1, sn*l.2);
+        let dv = linalg::v_add(linalg::v_mult_r(m, sn), linalg::v_mult_r(l, dd/2_f32*cs));
+        let d = QNum::make_from_r(-dd/2_f32*sn, dv.0, dv.1, dv.2);
+        DualQNum{r, d}
+    }
+    ///The function for screw linear interpolation (ScLERP) between the unit dual quaternions 'a' and 'b'. The shortest path is taken
+    ///
+    ///Функция винтовой линейной интерполяции (ScLERP) между единичными дуальными кватернионами 'a' и 'b'. Выбирается кратчайший путь
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_identity();
+    /// //Винтовое движение: поворот на 1 радиан вокруг оси z и смещение на 2 вдоль нее
+    /// let b = DualQNum::make_from_rt(UnitQNum::make_from_a(1_f32, (0_f32, 0_f32, 1_f32)), (0_f32, 0_f32, 2_f32));
+    /// let c = DualQNum::sclerp(a, b, 0.5_f32);
+    /// let (x, y, z) = c.transform_point((1_f32, 0_f32, 0_f32));
+    /// assert!((x-0.5_f32.cos()).abs() < 0.00001 && (y-0.5_f32.sin()).abs() < 0.00001 && (z-1_f32).abs() < 0.00001);
+    /// ```
+    pub fn sclerp(a:DualQNum, b:DualQNum, t:f32) -> DualQNum{
+        let diff = a.conj().mult_d(b);
+        let diff = if diff.r.get().0 < 0_f32 { -diff } else { diff };
+        a.mult_d(diff.pow(t)).normalize()
+    }
+    ///The function for dual quaternion linear blending (DLB) of the unit dual quaternions 'dqs' with the weights 'ws', as used for skinning. The signs are aligned with the first dual quaternion before the weighted sum is normalized
+    ///
+    ///Функция дуально-кватернионного линейного смешивания (DLB) единичных дуальных кватернионов 'dqs' с весами 'ws', применяемого для скиннинга. Перед нормализацией взвешенной суммы знаки выравниваются по первому дуальному кватерниону
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = DualQNum::make_from_rt(UnitQNum::make_from_a(0.4_f32, (0_f32, 0_f32, 1_f32)), (1_f32, 0_f32, 0_f32));
+    /// let b = -DualQNum::make_from_rt(UnitQNum::make_from_a(0.8_f32, (0_f32, 0_f32, 1_f32)), (1_f32, 0_f32, 0_f32));
+    /// let c = DualQNum::dlb(&[a, b], &[0.5_f32, 0.5_f32]);
+    /// let (ang, _) = c.get_rotation().to_axis_angle();
+    /// assert!((ang-0.6_f32).abs() < 0.00001);
+    /// let (x, y, z) = c.get_translation();
+    /// assert!((x-1_f32).abs() < 0.00001 && y.abs() < 0.00001 && z.abs() < 0.00001);
+    /// ```
+    pub fn dlb(dqs:&[DualQNum], ws:&[f32]) -> DualQNum{
+        assert!(!dqs.is_empty());
+        assert_eq!(dqs.len(), ws.len());
+        let mut sum = DualQNum{r:QNum::make_zero(), d:QNum::make_zero()};
+        for (dq, w) in dqs.iter().zip(ws) {
+            let w = if dq.r.dot(dqs[0].r.clone()) < 0_f32 { -w } else { *w };
+            sum = sum.add_d(dq.mult_r(w));
+        }
+        sum.normalize()
+    }
+}
+
+impl PartialEq for DualQNum{
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.d == other.d
+    }
+}
+impl Neg for DualQNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::dual_quaternion::DualQNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = -DualQNum::make(QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32), QNum::make_from_r(0_f32, 1_f32, 0_f32, 0_f32));
+    /// assert!(a == DualQNum::make(QNum::make_from_r(-1_f32, 0_f32, 0_f32, 0_f32), QNum::make_from_r(0_f32, -1_f32, 0_f32, 0_f32)));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1_f32)
+    }
+}
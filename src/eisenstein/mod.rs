@@ -0,0 +1,313 @@
+//!Eisenstein integers
+//!
+//!Целые числа Эйзенштейна
+use std::ops::Neg;
+use crate::arith;
+use crate::complex::CNum;
+
+///Structure for storing the Eisenstein integer a + b·ω with integer coefficients, where ω = (-1 + i·√3)/2 is the cube root of unity (ω² = -1 - ω). The numbers form the hexagonal lattice. The arithmetic is exact while the norms fit in i64
+///
+/// Структура для хранения целого числа Эйзенштейна a + b·ω с целыми коэффициентами, где ω = (-1 + i·√3)/2 - кубический корень из единицы (ω² = -1 - ω). Числа образуют шестиугольную решетку. Арифметика точна, пока нормы помещаются в i64
+pub struct ENum {
+    a:i64,
+    b:i64
+}
+
+impl ENum {
+    ///The function for creating an Eisenstein integer equal to zero
+    ///
+    ///Функция для создания целого числа Эйзенштейна, равного нулю
+    pub fn make_zero() -> Self{ ENum{a:0, b:0} }
+    ///The function for creating an Eisenstein integer a + b·ω
+    ///
+    ///Функция для создания целого числа Эйзенштейна a + b·ω
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let z = ENum::make(4, -2);
+    /// assert_eq!((4, -2), z.get());
+    /// ```
+    pub fn make(a:i64, b:i64) -> Self{ ENum{a, b} }
+    ///The function that returns the lattice point nearest to the complex number 'c'
+    ///
+    ///Функция, возвращающая точку решетки, ближайшую к комплексному числу 'c'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// use tmn::complex::CNum;
+    /// assert!(ENum::make(0, 1) == ENum::make_from_c(CNum::make(-0.45_f32, 0.8_f32)));
+    /// assert!(ENum::make(2, -1) == ENum::make_from_c(CNum::make(2.4_f32, -0.9_f32)));
+    /// //Точки решетки переходят сами в себя
+    /// for a in -5..5 {
+    ///     for b in -5..5 {
+    ///         assert!(ENum::make(a, b) == ENum::make_from_c(ENum::make(a, b).to_cnum()));
+    ///     }
+    /// }
+    /// ```
+    pub fn make_from_c(c:CNum) -> Self{
+        let (x, y) = c.get();
+        let b = (y as f64)*2_f64/3_f64.sqrt();
+        let a = (x as f64) + b/2_f64;
+        //Ближайшая точка - одна из вершин ячейки, разбитой на два правильных треугольника
+        let mut best = (f64::INFINITY, ENum::make_zero());
+        for (da, db) in [(0_f64, 0_f64), (1_f64, 0_f64), (0_f64, 1_f64), (1_f64, 1_f64)] {
+            let (ca, cb) = (a.floor() + da, b.floor() + db);
+            let (ea, eb) = (a - ca, b - cb);
+            let d = ea*ea - ea*eb + eb*eb;
+            if d < best.0 {
+                best = (d, ENum::make(ca as i64, cb as i64));
+            }
+        }
+        best.1
+    }
+    ///The method for cloning an Eisenstein integer
+    ///
+    ///Метод для клонирования целого числа Эйзенштейна
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> ENum{ ENum{a:self.a, b:self.b} }
+    ///The method that returns the coefficients a and b of the number a + b·ω
+    ///
+    ///Метод, возвращающий коэффициенты a и b числа a + b·ω
+    pub fn get(&self) -> (i64, i64){ (self.a, self.b) }
+    ///The method that converts the Eisenstein integer to a complex number
+    ///
+    ///Метод, преобразующий целое число Эйзенштейна в комплексное
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let (r, i) = ENum::make(0, 1).to_cnum().get();
+    /// assert!((r+0.5_f32).abs() < 0.000001 && (i-3_f32.sqrt()/2_f32).abs() < 0.000001);
+    /// ```
+    pub fn to_cnum(&self) -> CNum{
+        let (a, b) = (self.a as f64, self.b as f64);
+        CNum::make((a - b/2_f64) as f32, (b*3_f64.sqrt()/2_f64) as f32)
+    }
+    ///The method that returns the conjugate number a + b·ω² = (a - b) - b·ω
+    ///
+    ///Метод, возвращающий сопряженное число a + b·ω² = (a - b) - b·ω
+    pub fn conj(&self) -> ENum{ ENum{a:self.a - self.b, b:-self.b} }
+    ///The method that returns the norm a² - ab + b²
+    ///
+    ///Метод, возвращающий норму a² - ab + b²
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// assert_eq!(7, ENum::make(3, 1).norm());
+    /// assert_eq!(1, ENum::make(0, 1).norm());
+    /// ```
+    pub fn norm(&self) -> i64{
+        let (a, b) = (self.a as i128, self.b as i128);
+        (a*a - a*b + b*b) as i64
+    }
+    ///The method that checks if the number is a unit (±1, ±ω or ±ω²)
+    ///
+    ///Метод, проверяющий, является ли число единицей (±1, ±ω или ±ω²)
+    pub fn is_unit(&self) -> bool{ self.norm() == 1 }
+    ///The method that returns the sum of Eisenstein integers
+    ///
+    ///Метод, возвращающий сумму целых чисел Эйзенштейна
+    pub fn add_e(&self, v:ENum) -> ENum{ ENum{a:self.a + v.a, b:self.b + v.b} }
+    ///The method that returns the product of an Eisenstein integer and an integer
+    ///
+    ///Метод, возвращающий произведение целого числа Эйзенштейна и целого числа
+    pub fn mult_r(&self, v:i64) -> ENum{ ENum{a:self.a*v, b:self.b*v} }
+    ///The method that returns the product of Eisenstein integers
+    ///
+    ///Метод, возвращающий произведение целых чисел Эйзенштейна
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let w = ENum::make(0, 1);
+    /// //ω² = -1 - ω, ω³ = 1
+    /// assert!(ENum::make(-1, -1) == w.mult_e(w.clone()));
+    /// assert!(ENum::make(1, 0) == w.mult_e(w.clone()).mult_e(w));
+    /// //Согласованность с комплексным умножением
+    /// let (x, y) = (ENum::make(3, -2), ENum::make(1, 4));
+    /// let (r, i) = x.to_cnum().mult_c(y.to_cnum()).get();
+    /// let (r1, i1) = x.mult_e(y).to_cnum().get();
+    /// assert!((r-r1).abs() < 0.0001 && (i-i1).abs() < 0.0001);
+    /// ```
+    pub fn mult_e(&self, v:ENum) -> ENum{
+        ENum{
+            a:self.a*v.a - self.b*v.b,
+            b:self.a*v.b + self.b*v.a - self.b*v.b
+        }
+    }
+    ///The method of the Euclidean division: returns the quotient q and the remainder r such that self = q·v + r and N(r) ≤ N(v)/3. The quotient is the lattice point nearest to self/v. The divisor must not be zero
+    ///
+    ///Метод евклидова деления: возвращает частное q и остаток r, такие что self = q·v + r и N(r) ≤ N(v)/3. Частное - точка решетки, ближайшая к self/v. Делитель не должен быть равен нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let x = ENum::make(31, -17);
+    /// let v = ENum::make(4, 7);
+    /// let (q, r) = x.div_rem(v.clone());
+    /// assert!(q.mult_e(v.clone()).add_e(r.clone()) == x);
+    /// assert!(3*r.norm() <= v.norm());
+    /// ```
+    pub fn div_rem(&self, v:ENum) -> (ENum, ENum){
+        let n = v.norm() as i128;
+        assert!(n != 0);
+        //self/v = (p + q·ω)/n
+        let c = v.conj();
+        let (a, b, ca, cb) = (self.a as i128, self.b as i128, c.a as i128, c.b as i128);
+        let (p, q) = (a*ca - b*cb, a*cb + b*ca - b*cb);
+        let mut best = (u128::MAX, 0_i128, 0_i128);
+        for (da, db) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let (ka, kb) = (p.div_euclid(n) + da, q.div_euclid(n) + db);
+            let (ea, eb) = (p - ka*n, q - kb*n);
+            //Значение формы лежит в [0, 3n²) и помещается в u128, поэтому переполнение i128 при вычислении безопасно
+            let d = ea.wrapping_mul(ea).wrapping_sub(ea.wrapping_mul(eb)).wrapping_add(eb.wrapping_mul(eb)) as u128;
+            if d < best.0 {
+                best = (d, ka, kb);
+            }
+        }
+        let k = ENum{a:best.1 as i64, b:best.2 as i64};
+        let r = self.add_e(-k.mult_e(v));
+        (k, r)
+    }
+    //Единица u, такая что u·self лежит в секторе 0 ≤ arg < 60° (a > b ≥ 0); для нуля - 1
+    fn normalizing_unit(&self) -> ENum{
+        let units = [(1, 0), (0, 1), (-1, -1), (-1, 0), (0, -1), (1, 1)];
+        for (a, b) in units {
+            let u = ENum::make(a, b);
+            let z = self.mult_e(u.clone());
+            if z.a > z.b && z.b >= 0 {
+                return u;
+            }
+        }
+        ENum::make(1, 0)
+    }
+    ///The method that returns the associated number (the number multiplied by a unit) lying in the sector 0 ≤ arg < 60°, that is a > b ≥ 0
+    ///
+    ///Метод, возвращающий ассоциированное число (число, умноженное на единицу), лежащее в секторе 0 ≤ arg < 60°, то есть a > b ≥ 0
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let z = ENum::make(3, 1);
+    /// let w = ENum::make(0, 1);
+    /// assert!(z == z.mult_e(w.clone()).normalize());
+    /// assert!(z == (-z.mult_e(w.clone()).mult_e(w)).normalize());
+    /// ```
+    pub fn normalize(&self) -> ENum{ self.mult_e(self.normalizing_unit()) }
+    ///The function that returns the greatest common divisor, normalized to the sector 0 ≤ arg < 60°
+    ///
+    ///Функция, возвращающая наибольший общий делитель, нормализованный в сектор 0 ≤ arg < 60°
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let g = ENum::make(3, 1);
+    /// let x = g.mult_e(ENum::make(2, 0));
+    /// let y = g.mult_e(ENum::make(1, -2));
+    /// assert!(g == ENum::gcd(x, y));
+    /// ```
+    pub fn gcd(a:ENum, b:ENum) -> ENum{
+        let (mut a, mut b) = (a, b);
+        while b.norm() != 0 {
+            let (_, r) = a.div_rem(b.clone());
+            a = b;
+            b = r;
+        }
+        a.normalize()
+    }
+    ///The method that checks if the number is an Eisenstein prime: either its norm is a prime, or it is associated with a prime p = 2 (mod 3)
+    ///
+    ///Метод, проверяющий, является ли число простым числом Эйзенштейна: либо его норма простая, либо оно ассоциировано с простым p = 2 (mod 3)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// assert!(ENum::make(1, -1).is_prime());
+    /// assert!(ENum::make(2, 0).is_prime());
+    /// assert!(ENum::make(3, 1).is_prime());
+    /// assert!(!ENum::make(7, 0).is_prime());
+    /// assert!(!ENum::make(3, 0).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool{
+        let n = self.norm() as u64;
+        if arith::is_prime(n) {
+            return true;
+        }
+        let p = arith::isqrt(n);
+        p*p == n && p%3 == 2 && arith::is_prime(p)
+    }
+    ///The method that factors the number into Eisenstein primes: returns the unit u and the primes (normalized to the sector 0 ≤ arg < 60°, sorted by norm, with repetitions) such that self = u·p1·p2·... The number must not be zero
+    ///
+    ///Метод, раскладывающий число на простые числа Эйзенштейна: возвращает единицу u и простые множители (нормализованные в сектор 0 ≤ arg < 60°, упорядоченные по норме, с повторениями), такие что self = u·p1·p2·... Число не должно быть равно нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// let z = ENum::make(42, -12);
+    /// let (u, ps) = z.factor();
+    /// let mut p = u.clone();
+    /// for f in &ps {
+    ///     assert!(f.is_prime());
+    ///     p = p.mult_e(f.clone());
+    /// }
+    /// assert!(p == z);
+    /// assert!(u.is_unit());
+    /// ```
+    pub fn factor(&self) -> (ENum, Vec<ENum>){
+        assert!(self.norm() != 0);
+        let mut ret = Vec::new();
+        let mut rest = self.clone();
+        for (p, e) in arith::factor(self.norm() as u64) {
+            let candidates = if p == 3 {
+                vec![ENum::make(2, 1)]
+            } else if p%3 == 2 {
+                vec![ENum::make(p as i64, 0)]
+            } else {
+                //p = u² + 3v² = N(u + v + 2v·ω)
+                let (u, v) = arith::cornacchia(3, p);
+                let pi = ENum::make((u + v) as i64, 2*v as i64).normalize();
+                vec![pi.clone(), pi.conj().normalize()]
+            };
+            //Деление на простые множители нормы p, пока оно точное
+            let mut left = e;
+            for c in candidates {
+                loop {
+                    let (q, r) = rest.div_rem(c.clone());
+                    if r.norm() != 0 || left == 0 {
+                        break;
+                    }
+                    rest = q;
+                    ret.push(c.clone());
+                    left -= if c.norm() == p as i64 { 1 } else { 2 };
+                }
+            }
+        }
+        ret.sort_by_key(|z| (z.norm(), z.a));
+        (rest, ret)
+    }
+}
+
+impl PartialEq for ENum {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Neg for ENum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::eisenstein::ENum;
+    /// assert!(ENum::make(-3, 4) == -ENum::make(3, -4));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1)
+    }
+}
@@ -0,0 +1,320 @@
+//!Gaussian integers
+//!
+//!Гауссовы целые числа
+use std::ops::Neg;
+use crate::arith;
+use crate::complex::CNum;
+
+///Structure for storing the Gaussian integer r + i·i with integer coefficients. The arithmetic is exact while the norms fit in i64 (the coefficients are less than ~3·10⁹ in absolute value)
+///
+/// Структура для хранения гауссова целого числа r + i·i с целыми коэффициентами. Арифметика точна, пока нормы помещаются в i64 (коэффициенты по модулю меньше ~3·10⁹)
+pub struct GNum {
+    r:i64,
+    i:i64
+}
+
+impl GNum {
+    ///The function for creating a Gaussian integer equal to zero
+    ///
+    ///Функция для создания гауссова целого числа, равного нулю
+    pub fn make_zero() -> Self{ GNum{r:0, i:0} }
+    ///The function for creating a Gaussian integer from the real and imaginary parts
+    ///
+    ///Функция для создания гауссова целого числа из действительной и мнимой части
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// let a = GNum::make(4, -2);
+    /// assert_eq!((4, -2), a.get());
+    /// ```
+    pub fn make(r:i64, i:i64) -> Self{ GNum{r, i} }
+    ///The method for cloning a Gaussian integer
+    ///
+    ///Метод для клонирования гауссова целого числа
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> GNum{ GNum{r:self.r, i:self.i} }
+    ///The method that returns the coefficients of a Gaussian integer
+    ///
+    ///Метод, возвращающий коэффициенты гауссова целого числа
+    pub fn get(&self) -> (i64, i64){ (self.r, self.i) }
+    ///The method that converts the Gaussian integer to a complex number
+    ///
+    ///Метод, преобразующий гауссово целое число в комплексное
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// use tmn::complex::CNum;
+    /// assert!(CNum::make(3_f32, -7_f32) == GNum::make(3, -7).to_cnum());
+    /// ```
+    pub fn to_cnum(&self) -> CNum{ CNum::make(self.r as f32, self.i as f32) }
+    ///The method that returns the conjugate number
+    ///
+    ///Метод, возвращающий сопряженное число
+    pub fn conj(&self) -> GNum{ GNum{r:self.r, i:-self.i} }
+    ///The method that returns the norm r² + i²
+    ///
+    ///Метод, возвращающий норму r² + i²
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// assert_eq!(25, GNum::make(3, -4).norm());
+    /// ```
+    pub fn norm(&self) -> i64{ self.r*self.r + self.i*self.i }
+    ///The method that checks if the number is a unit (1, -1, i or -i)
+    ///
+    ///Метод, проверяющий, является ли число единицей (1, -1, i или -i)
+    pub fn is_unit(&self) -> bool{ self.norm() == 1 }
+    ///The method that returns the sum of Gaussian integers
+    ///
+    ///Метод, возвращающий сумму гауссовых целых чисел
+    pub fn add_g(&self, v:GNum) -> GNum{ GNum{r:self.r + v.r, i:self.i + v.i} }
+    ///The method that returns the product of a Gaussian integer and an integer
+    ///
+    ///Метод, возвращающий произведение гауссова целого числа и целого числа
+    pub fn mult_r(&self, v:i64) -> GNum{ GNum{r:self.r*v, i:self.i*v} }
+    ///The method that returns the product of Gaussian integers
+    ///
+    ///Метод, возвращающий произведение гауссовых целых чисел
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// assert!(GNum::make(9, 19) == GNum::make(3, 2).mult_g(GNum::make(5, 3)));
+    /// ```
+    pub fn mult_g(&self, v:GNum) -> GNum{
+        GNum{
+            r:self.r*v.r - self.i*v.i,
+            i:self.r*v.i + self.i*v.r
+        }
+    }
+    ///The method of the Euclidean division: returns the quotient q and the remainder r such that self = q·v + r and N(r) ≤ N(v)/2. The quotient is the nearest Gaussian integer to self/v. The divisor must not be zero
+    ///
+    ///Метод евклидова деления: возвращает частное q и остаток r, такие что self = q·v + r и N(r) ≤ N(v)/2. Частное - ближайшее гауссово целое к self/v. Делитель не должен быть равен нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// let a = GNum::make(27, -23);
+    /// let b = GNum::make(8, 1);
+    /// let (q, r) = a.div_rem(b.clone());
+    /// assert!(q.mult_g(b.clone()).add_g(r.clone()) == a);
+    /// assert!(2*r.norm() <= b.norm());
+    /// //Точное деление
+    /// let (q, r) = GNum::make(9, 19).div_rem(GNum::make(5, 3));
+    /// assert!(q == GNum::make(3, 2) && r == GNum::make_zero());
+    /// ```
+    pub fn div_rem(&self, v:GNum) -> (GNum, GNum){
+        let n = v.norm() as i128;
+        assert!(n != 0);
+        let num_r = (self.r as i128)*(v.r as i128) + (self.i as i128)*(v.i as i128);
+        let num_i = (self.i as i128)*(v.r as i128) - (self.r as i128)*(v.i as i128);
+        let q = GNum{r:arith::div_round(num_r, n) as i64, i:arith::div_round(num_i, n) as i64};
+        let r = self.add_g(-q.mult_g(v));
+        (q, r)
+    }
+    //Единица u, такая что u·self лежит в первой четверти (r > 0, i ≥ 0); для нуля - 1
+    fn normalizing_unit(&self) -> GNum{
+        match (self.r, self.i) {
+            (r, i) if r > 0 && i >= 0 => GNum::make(1, 0),
+            (r, i) if r <= 0 && i > 0 => GNum::make(0, -1),
+            (r, i) if r < 0 && i <= 0 => GNum::make(-1, 0),
+            (0, 0) => GNum::make(1, 0),
+            _ => GNum::make(0, 1)
+        }
+    }
+    ///The method that returns the associated number (the number multiplied by a unit) lying in the first quadrant: r > 0, i ≥ 0
+    ///
+    ///Метод, возвращающий ассоциированное число (число, умноженное на единицу), лежащее в первой четверти: r > 0, i ≥ 0
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// assert!(GNum::make(2, 3) == GNum::make(3, -2).normalize());
+    /// assert!(GNum::make(2, 3) == GNum::make(-2, -3).normalize());
+    /// ```
+    pub fn normalize(&self) -> GNum{ self.mult_g(self.normalizing_unit()) }
+    ///The function that returns the greatest common divisor, normalized to the first quadrant
+    ///
+    ///Функция, возвращающая наибольший общий делитель, нормализованный в первую четверть
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// //(2 + 3i)(1 - i) и (2 + 3i)(4 + i)
+    /// let g = GNum::gcd(GNum::make(5, 1), GNum::make(5, 14));
+    /// assert!(GNum::make(2, 3) == g);
+    /// ```
+    pub fn gcd(a:GNum, b:GNum) -> GNum{
+        let (mut a, mut b) = (a, b);
+        while b.norm() != 0 {
+            let (_, r) = a.div_rem(b.clone());
+            a = b;
+            b = r;
+        }
+        a.normalize()
+    }
+    ///The function of the extended Euclidean algorithm: returns (g, x, y) such that a·x + b·y = g, where g is the greatest common divisor normalized to the first quadrant
+    ///
+    ///Функция расширенного алгоритма Евклида: возвращает (g, x, y), такие что a·x + b·y = g, где g - наибольший общий делитель, нормализованный в первую четверть
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// let (a, b) = (GNum::make(5, 1), GNum::make(5, 14));
+    /// let (g, x, y) = GNum::xgcd(a.clone(), b.clone());
+    /// assert!(GNum::make(2, 3) == g);
+    /// assert!(a.mult_g(x).add_g(b.mult_g(y)) == g);
+    /// ```
+    pub fn xgcd(a:GNum, b:GNum) -> (GNum, GNum, GNum){
+        let (mut r0, mut r1) = (a, b);
+        let (mut x0, mut x1) = (GNum::make(1, 0), GNum::make_zero());
+        let (mut y0, mut y1) = (GNum::make_zero(), GNum::make(1, 0));
+        while r1.norm() != 0 {
+            let (q, r) = r0.div_rem(r1.clone());
+            let x = x0.add_g(-q.mult_g(x1.clone()));
+            let y = y0.add_g(-q.mult_g(y1.clone()));
+            (r0, r1) = (r1, r);
+            (x0, x1) = (x1, x);
+            (y0, y1) = (y1, y);
+        }
+        let u = r0.normalizing_unit();
+        (r0.mult_g(u.clone()), x0.mult_g(u.clone()), y0.mult_g(u))
+    }
+    ///The method that checks if the number is a Gaussian prime: either its norm is a prime, or it is associated with a prime p = 3 (mod 4)
+    ///
+    ///Метод, проверяющий, является ли число гауссовым простым: либо его норма простая, либо оно ассоциировано с простым p = 3 (mod 4)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// assert!(GNum::make(1, 1).is_prime());
+    /// assert!(GNum::make(0, -3).is_prime());
+    /// assert!(GNum::make(2, 3).is_prime());
+    /// assert!(!GNum::make(5, 0).is_prime());
+    /// assert!(!GNum::make(3, 3).is_prime());
+    /// assert!(!GNum::make(0, 1).is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool{
+        match (self.r.unsigned_abs(), self.i.unsigned_abs()) {
+            (0, p) | (p, 0) => p%4 == 3 && arith::is_prime(p),
+            _ => arith::is_prime(self.norm() as u64)
+        }
+    }
+    ///The method that factors the number into Gaussian primes: returns the unit u and the primes (normalized to the first quadrant, sorted by norm, with repetitions) such that self = u·p1·p2·... The number must not be zero
+    ///
+    ///Метод, раскладывающий число на гауссовы простые: возвращает единицу u и простые множители (нормализованные в первую четверть, упорядоченные по норме, с повторениями), такие что self = u·p1·p2·... Число не должно быть равно нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// let a = GNum::make(-30, 60);
+    /// let (u, ps) = a.factor();
+    /// let mut p = u.clone();
+    /// for f in &ps {
+    ///     assert!(f.is_prime());
+    ///     p = p.mult_g(f.clone());
+    /// }
+    /// assert!(p == a);
+    /// assert!(u.is_unit());
+    /// //Норма 4500 = 2²·3²·5³: (1+i)² · 3 · три множителя нормы 5
+    /// assert_eq!(6, ps.len());
+    /// ```
+    pub fn factor(&self) -> (GNum, Vec<GNum>){
+        assert!(self.norm() != 0);
+        let mut ret = Vec::new();
+        let mut rest = self.clone();
+        for (p, e) in arith::factor(self.norm() as u64) {
+            let candidates = if p == 2 {
+                vec![GNum::make(1, 1)]
+            } else if p%4 == 3 {
+                vec![GNum::make(p as i64, 0)]
+            } else {
+                let (a, b) = arith::cornacchia(1, p);
+                let pi = GNum::make(a as i64, b as i64);
+                vec![pi.clone(), pi.conj().normalize()]
+            };
+            //Деление на простые множители нормы p, пока оно точное
+            let mut left = e;
+            for c in candidates {
+                loop {
+                    let (q, r) = rest.div_rem(c.clone());
+                    if r.norm() != 0 || left == 0 {
+                        break;
+                    }
+                    rest = q;
+                    ret.push(c.clone());
+                    left -= if c.norm() == p as i64 { 1 } else { 2 };
+                }
+            }
+        }
+        ret.sort_by_key(|g| (g.norm(), g.r));
+        (rest, ret)
+    }
+    ///The function that represents the natural number 'n' as a sum of two squares a² + b² (a ≥ b ≥ 0). None is returned if it is impossible: some prime p = 3 (mod 4) appears in 'n' with an odd power. The factorization of 'n' uses trial division, so it is slow when 'n' has two prime factors greater than ~2²⁶
+    ///
+    ///Функция, представляющая натуральное число 'n' в виде суммы двух квадратов a² + b² (a ≥ b ≥ 0). Возвращается None, если это невозможно: некоторое простое p = 3 (mod 4) входит в 'n' в нечетной степени. Разложение 'n' выполняется пробным делением, поэтому медленно, если у 'n' есть два простых множителя больше ~2²⁶
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// assert_eq!(Some((4, 1)), GNum::two_squares(17));
+    /// let (a, b) = GNum::two_squares(1105).unwrap();
+    /// assert_eq!(1105, a*a + b*b);
+    /// assert_eq!(Some((3, 3)), GNum::two_squares(18));
+    /// assert_eq!(None, GNum::two_squares(21));
+    /// assert_eq!(Some((0, 0)), GNum::two_squares(0));
+    /// //Большое простое p = 1 (mod 4)
+    /// let (a, b) = GNum::two_squares(18446744073709551557).unwrap();
+    /// assert_eq!(18446744073709551557, (a as u128)*(a as u128) + (b as u128)*(b as u128));
+    /// ```
+    pub fn two_squares(n:u64) -> Option<(i64, i64)>{
+        if n == 0 {
+            return Some((0, 0));
+        }
+        let mut z = GNum::make(1, 0);
+        for (p, e) in arith::factor(n) {
+            let f = if p == 2 {
+                GNum::make(1, 1)
+            } else if p%4 == 3 {
+                if e%2 == 1 {
+                    return None;
+                }
+                z = z.mult_r((p as i64).pow(e/2));
+                continue;
+            } else {
+                let (a, b) = arith::cornacchia(1, p);
+                GNum::make(a as i64, b as i64)
+            };
+            for _ in 0..e {
+                z = z.mult_g(f.clone());
+            }
+        }
+        let (a, b) = (z.r.abs(), z.i.abs());
+        Some((a.max(b), a.min(b)))
+    }
+}
+
+impl PartialEq for GNum {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Neg for GNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::gaussian::GNum;
+    /// assert!(GNum::make(-3, 4) == -GNum::make(3, -4));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1)
+    }
+}
@@ -0,0 +1,519 @@
+//!Lipschitz and Hurwitz integer quaternions
+//!
+//!Целочисленные кватернионы Липшица и Гурвица
+use std::ops::Neg;
+use crate::arith;
+use crate::quaternion::QNum;
+
+//Произведение кватернионов с целыми коэффициентами (r, i, j, k)
+fn mult(a:[i64; 4], b:[i64; 4]) -> [i64; 4]{
+    [
+        a[0]*b[0] - a[1]*b[1] - a[2]*b[2] - a[3]*b[3],
+        a[0]*b[1] + a[1]*b[0] + a[2]*b[3] - a[3]*b[2],
+        a[0]*b[2] - a[1]*b[3] + a[2]*b[0] + a[3]*b[1],
+        a[0]*b[3] + a[1]*b[2] - a[2]*b[1] + a[3]*b[0]
+    ]
+}
+
+//То же с расширением до i128 (для деления, где промежуточная норма равна произведению норм)
+fn mult_wide(a:[i64; 4], b:[i64; 4]) -> [i128; 4]{
+    let (a, b) = (a.map(|x| x as i128), b.map(|x| x as i128));
+    [
+        a[0]*b[0] - a[1]*b[1] - a[2]*b[2] - a[3]*b[3],
+        a[0]*b[1] + a[1]*b[0] + a[2]*b[3] - a[3]*b[2],
+        a[0]*b[2] - a[1]*b[3] + a[2]*b[0] + a[3]*b[1],
+        a[0]*b[3] + a[1]*b[2] - a[2]*b[1] + a[3]*b[0]
+    ]
+}
+
+fn conj(a:[i64; 4]) -> [i64; 4]{ [a[0], -a[1], -a[2], -a[3]] }
+
+//Сумма квадратов в i128
+fn sum_squares(a:[i64; 4]) -> i128{ a.iter().map(|&x| (x as i128)*(x as i128)).sum() }
+
+///Structure for storing the Lipschitz quaternion r + i·i + j·j + k·k with integer coefficients. The arithmetic is exact while the norms fit in i64
+///
+/// Структура для хранения кватерниона Липшица r + i·i + j·j + k·k с целыми коэффициентами. Арифметика точна, пока нормы помещаются в i64
+pub struct LNum{r:i64, i:i64, j:i64, k:i64}
+
+impl LNum {
+    ///The function for creating a Lipschitz quaternion equal to zero
+    ///
+    ///Функция для создания кватерниона Липшица, равного нулю
+    pub fn make_zero() -> Self{ LNum{r:0, i:0, j:0, k:0} }
+    ///The function for creating a Lipschitz quaternion from integer coefficients
+    ///
+    ///Функция для создания кватерниона Липшица из целых коэффициентов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// let a = LNum::make(1, -2, 3, 4);
+    /// assert_eq!((1, -2, 3, 4), a.get());
+    /// ```
+    pub fn make(r:i64, i:i64, j:i64, k:i64) -> Self{ LNum{r, i, j, k} }
+    fn make_from_array(a:[i64; 4]) -> Self{ LNum{r:a[0], i:a[1], j:a[2], k:a[3]} }
+    fn to_array(&self) -> [i64; 4]{ [self.r, self.i, self.j, self.k] }
+    ///The method for cloning a Lipschitz quaternion
+    ///
+    ///Метод для клонирования кватерниона Липшица
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> LNum{ LNum{r:self.r, i:self.i, j:self.j, k:self.k} }
+    ///The method that returns the coefficients
+    ///
+    ///Метод, возвращающий коэффициенты
+    pub fn get(&self) -> (i64, i64, i64, i64){ (self.r, self.i, self.j, self.k) }
+    ///The method that converts the Lipschitz quaternion to QNum
+    ///
+    ///Метод, преобразующий кватернион Липшица в QNum
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// assert_eq!((1_f32, -2_f32, 3_f32, 4_f32), LNum::make(1, -2, 3, 4).to_qnum().get());
+    /// ```
+    pub fn to_qnum(&self) -> QNum{ QNum::make_from_r(self.r as f32, self.i as f32, self.j as f32, self.k as f32) }
+    ///The method that converts the Lipschitz quaternion to the Hurwitz quaternion
+    ///
+    ///Метод, преобразующий кватернион Липшица в кватернион Гурвица
+    pub fn to_hnum(&self) -> HNum{ HNum::make(self.r, self.i, self.j, self.k) }
+    ///The method that returns the conjugate quaternion
+    ///
+    ///Метод, возвращающий сопряженный кватернион
+    pub fn conj(&self) -> LNum{ LNum::make_from_array(conj(self.to_array())) }
+    ///The method that returns the norm r² + i² + j² + k²
+    ///
+    ///Метод, возвращающий норму r² + i² + j² + k²
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// assert_eq!(30, LNum::make(1, -2, 3, 4).norm());
+    /// ```
+    pub fn norm(&self) -> i64{ self.r*self.r + self.i*self.i + self.j*self.j + self.k*self.k }
+    ///The method that checks if the quaternion is a unit (±1, ±i, ±j or ±k)
+    ///
+    ///Метод, проверяющий, является ли кватернион единицей (±1, ±i, ±j или ±k)
+    pub fn is_unit(&self) -> bool{ self.norm() == 1 }
+    ///The method that returns the sum of Lipschitz quaternions
+    ///
+    ///Метод, возвращающий сумму кватернионов Липшица
+    pub fn add_l(&self, v:LNum) -> LNum{ LNum{r:self.r + v.r, i:self.i + v.i, j:self.j + v.j, k:self.k + v.k} }
+    ///The method that returns the product of a Lipschitz quaternion and an integer
+    ///
+    ///Метод, возвращающий произведение кватерниона Липшица и целого числа
+    pub fn mult_r(&self, v:i64) -> LNum{ LNum{r:self.r*v, i:self.i*v, j:self.j*v, k:self.k*v} }
+    ///The method that returns the product of Lipschitz quaternions (as 'QNum::mult_q')
+    ///
+    ///Метод, возвращающий произведение кватернионов Липшица (как 'QNum::mult_q')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// use tmn::quaternion::QNum;
+    /// let (a, b) = (LNum::make(1, -2, 3, 4), LNum::make(-2, 1, 4, 1));
+    /// assert!(a.mult_l(b.clone()).to_qnum() == a.to_qnum().mult_q(b.to_qnum()));
+    /// //Норма мультипликативна
+    /// assert_eq!(a.norm()*b.norm(), a.mult_l(b).norm());
+    /// ```
+    pub fn mult_l(&self, v:LNum) -> LNum{ LNum::make_from_array(mult(self.to_array(), v.to_array())) }
+    //Частное: ближайший кватернион Липшица к p/n
+    fn round(p:[i128; 4], n:i128) -> LNum{
+        let c = |x:i128| arith::div_round(x, n) as i64;
+        LNum::make(c(p[0]), c(p[1]), c(p[2]), c(p[3]))
+    }
+    ///The method of the right division: returns the quotient q and the remainder r such that self = q·v + r. The quotient is the nearest Lipschitz quaternion to self·v⁻¹, and N(r) ≤ N(v). The Lipschitz quaternions are not a Euclidean ring: the remainder can have the same norm as the divisor (use 'HNum' for the strict inequality). The divisor must not be zero
+    ///
+    ///Метод правого деления: возвращает частное q и остаток r, такие что self = q·v + r. Частное - ближайший кватернион Липшица к self·v⁻¹, и N(r) ≤ N(v). Кватернионы Липшица не образуют евклидово кольцо: остаток может иметь ту же норму, что и делитель (для строгого неравенства используйте 'HNum'). Делитель не должен быть равен нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// let (a, v) = (LNum::make(17, -5, 8, 3), LNum::make(2, 1, -1, 3));
+    /// let (q, r) = a.div_rem_right(v.clone());
+    /// assert!(q.mult_l(v.clone()).add_l(r.clone()) == a);
+    /// assert!(r.norm() <= v.norm());
+    /// //Тупиковый случай: (1 + i + j + k)/2 равноудален от всех кватернионов Липшица
+    /// let (_, r) = LNum::make(1, 1, 1, 1).div_rem_right(LNum::make(2, 0, 0, 0));
+    /// assert_eq!(4, r.norm());
+    /// ```
+    pub fn div_rem_right(&self, v:LNum) -> (LNum, LNum){
+        let n = sum_squares(v.to_array());
+        assert!(n != 0);
+        let q = LNum::round(mult_wide(self.to_array(), conj(v.to_array())), n);
+        let r = self.add_l(-q.mult_l(v));
+        (q, r)
+    }
+    ///The method of the left division: returns the quotient q and the remainder r such that self = v·q + r and N(r) ≤ N(v) (see 'div_rem_right')
+    ///
+    ///Метод левого деления: возвращает частное q и остаток r, такие что self = v·q + r и N(r) ≤ N(v) (см. 'div_rem_right')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// let (a, v) = (LNum::make(17, -5, 8, 3), LNum::make(2, 1, -1, 3));
+    /// let (q, r) = a.div_rem_left(v.clone());
+    /// assert!(v.mult_l(q).add_l(r.clone()) == a);
+    /// assert!(r.norm() <= v.norm());
+    /// ```
+    pub fn div_rem_left(&self, v:LNum) -> (LNum, LNum){
+        let n = sum_squares(v.to_array());
+        assert!(n != 0);
+        let q = LNum::round(mult_wide(conj(v.to_array()), self.to_array()), n);
+        let r = self.add_l(-v.mult_l(q.clone()));
+        (q, r)
+    }
+    ///The function that returns the Lagrange decomposition of the natural number 'n' into the sum of four squares as the Lipschitz quaternion q with N(q) = n. It is built as the product of quaternions of prime norms. The prime factors of 'n' must be less than 2⁶⁰, otherwise the intermediate products overflow
+    ///
+    ///Функция, возвращающая разложение Лагранжа натурального числа 'n' в сумму четырех квадратов в виде кватерниона Липшица q с N(q) = n. Строится как произведение кватернионов простых норм. Простые множители 'n' должны быть меньше 2⁶⁰, иначе промежуточные произведения переполняются
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::LNum;
+    /// for n in [0_u64, 1, 7, 15, 28, 310, 1000003, 4294967291] {
+    ///     assert_eq!(n as i64, LNum::four_squares(n).norm());
+    /// }
+    /// ```
+    pub fn four_squares(n:u64) -> LNum{
+        if n == 0 {
+            return LNum::make_zero();
+        }
+        let mut ret = LNum::make(1, 0, 0, 0);
+        for (p, e) in arith::factor(n) {
+            let pi = HNum::prime_of_norm(p).to_lipschitz_associate();
+            for _ in 0..e {
+                ret = ret.mult_l(pi.clone());
+            }
+        }
+        ret
+    }
+}
+
+impl PartialEq for LNum {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Neg for LNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1)
+    }
+}
+
+///Structure for storing the Hurwitz quaternion: all coefficients are either integers or halves of odd integers. The doubled coefficients are stored. The arithmetic is exact while the norms fit in i64. The Hurwitz quaternions form a Euclidean ring (on both sides)
+///
+/// Структура для хранения кватерниона Гурвица: все коэффициенты либо целые, либо половины нечетных чисел. Хранятся удвоенные коэффициенты. Арифметика точна, пока нормы помещаются в i64. Кватернионы Гурвица образуют евклидово кольцо (с обеих сторон)
+pub struct HNum{d:[i64; 4]}
+
+impl HNum {
+    ///The function for creating a Hurwitz quaternion equal to zero
+    ///
+    ///Функция для создания кватерниона Гурвица, равного нулю
+    pub fn make_zero() -> Self{ HNum{d:[0; 4]} }
+    ///The function for creating a Hurwitz quaternion from integer coefficients
+    ///
+    ///Функция для создания кватерниона Гурвица из целых коэффициентов
+    pub fn make(r:i64, i:i64, j:i64, k:i64) -> Self{ HNum{d:[2*r, 2*i, 2*j, 2*k]} }
+    ///The function for creating a Hurwitz quaternion from the doubled coefficients, which must be all even or all odd
+    ///
+    ///Функция для создания кватерниона Гурвица из удвоенных коэффициентов, которые должны быть все четными или все нечетными
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// //(1 + i + j + k)/2
+    /// let a = HNum::make_from_doubled(1, 1, 1, 1);
+    /// assert_eq!((0.5_f32, 0.5_f32, 0.5_f32, 0.5_f32), a.to_qnum().get());
+    /// assert!(a.is_unit());
+    /// ```
+    pub fn make_from_doubled(r:i64, i:i64, j:i64, k:i64) -> Self{
+        let p = r.rem_euclid(2);
+        assert!(i.rem_euclid(2) == p && j.rem_euclid(2) == p && k.rem_euclid(2) == p);
+        HNum{d:[r, i, j, k]}
+    }
+    ///The method for cloning a Hurwitz quaternion
+    ///
+    ///Метод для клонирования кватерниона Гурвица
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> HNum{ HNum{d:self.d} }
+    ///The method that returns the doubled coefficients
+    ///
+    ///Метод, возвращающий удвоенные коэффициенты
+    pub fn get_doubled(&self) -> (i64, i64, i64, i64){ (self.d[0], self.d[1], self.d[2], self.d[3]) }
+    ///The method that converts the Hurwitz quaternion to QNum
+    ///
+    ///Метод, преобразующий кватернион Гурвица в QNum
+    pub fn to_qnum(&self) -> QNum{
+        QNum::make_from_r(self.d[0] as f32, self.d[1] as f32, self.d[2] as f32, self.d[3] as f32).mult_r(0.5_f32)
+    }
+    ///The method that converts the Hurwitz quaternion to the Lipschitz quaternion. None is returned for half-integer coefficients
+    ///
+    ///Метод, преобразующий кватернион Гурвица в кватернион Липшица. Для полуцелых коэффициентов возвращается None
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::{HNum, LNum};
+    /// assert!(Some(LNum::make(1, 2, 3, 4)) == HNum::make(1, 2, 3, 4).to_lnum());
+    /// assert!(HNum::make_from_doubled(1, 3, -1, 1).to_lnum().is_none());
+    /// ```
+    pub fn to_lnum(&self) -> Option<LNum>{
+        if self.d[0]%2 != 0 {
+            return None;
+        }
+        Some(LNum::make(self.d[0]/2, self.d[1]/2, self.d[2]/2, self.d[3]/2))
+    }
+    //Ассоциированный кватернион Липшица (умножение справа на единицу)
+    fn to_lipschitz_associate(&self) -> LNum{
+        for u in HNum::units() {
+            if let Some(l) = self.mult_h(u).to_lnum() {
+                return l;
+            }
+        }
+        unreachable!()
+    }
+    //24 единицы кольца Гурвица
+    fn units() -> Vec<HNum>{
+        let mut ret = Vec::new();
+        for c in 0..4 {
+            for s in [2, -2] {
+                let mut d = [0; 4];
+                d[c] = s;
+                ret.push(HNum{d});
+            }
+        }
+        for n in 0..16 {
+            let s = |b:i64| if (n >> b)&1 == 1 { -1 } else { 1 };
+            ret.push(HNum{d:[s(0), s(1), s(2), s(3)]});
+        }
+        ret
+    }
+    ///The method that returns the conjugate quaternion
+    ///
+    ///Метод, возвращающий сопряженный кватернион
+    pub fn conj(&self) -> HNum{ HNum{d:conj(self.d)} }
+    ///The method that returns the norm (an integer)
+    ///
+    ///Метод, возвращающий норму (целое число)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// assert_eq!(3, HNum::make_from_doubled(3, 1, -1, 1).norm());
+    /// ```
+    pub fn norm(&self) -> i64{ self.norm_wide() as i64 }
+    fn norm_wide(&self) -> i128{ sum_squares(self.d)/4 }
+    ///The method that checks if the quaternion is one of 24 units
+    ///
+    ///Метод, проверяющий, является ли кватернион одной из 24 единиц
+    pub fn is_unit(&self) -> bool{ self.norm() == 1 }
+    ///The method that checks if the quaternion is prime (its norm is a prime number)
+    ///
+    ///Метод, проверяющий, является ли кватернион простым (его норма - простое число)
+    pub fn is_prime(&self) -> bool{ arith::is_prime(self.norm() as u64) }
+    ///The method that returns the sum of Hurwitz quaternions
+    ///
+    ///Метод, возвращающий сумму кватернионов Гурвица
+    pub fn add_h(&self, v:HNum) -> HNum{
+        HNum{d:[self.d[0] + v.d[0], self.d[1] + v.d[1], self.d[2] + v.d[2], self.d[3] + v.d[3]]}
+    }
+    ///The method that returns the product of a Hurwitz quaternion and an integer
+    ///
+    ///Метод, возвращающий произведение кватерниона Гурвица и целого числа
+    pub fn mult_r(&self, v:i64) -> HNum{ HNum{d:self.d.map(|x| x*v)} }
+    ///The method that returns the product of Hurwitz quaternions (as 'QNum::mult_q')
+    ///
+    ///Метод, возвращающий произведение кватернионов Гурвица (как 'QNum::mult_q')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// let a = HNum::make_from_doubled(1, 3, -1, 5);
+    /// let b = HNum::make_from_doubled(-3, 1, 1, 1);
+    /// assert!(a.mult_h(b.clone()).to_qnum() == a.to_qnum().mult_q(b.to_qnum()));
+    /// assert_eq!(a.norm()*b.norm(), a.mult_h(b).norm());
+    /// ```
+    pub fn mult_h(&self, v:HNum) -> HNum{ HNum{d:mult(self.d, v.d).map(|x| x/2)} }
+    //Частное: ближайший кватернион Гурвица к p/(4n), где p - произведение удвоенных коэффициентов
+    fn round(p:[i128; 4], n:i128) -> HNum{
+        //Ближайшие целая и полуцелая точки (в удвоенных координатах)
+        let whole = p.map(|x| 2*arith::div_round(x, 4*n));
+        let half = p.map(|x| 2*x.div_euclid(4*n) + 1);
+        //Отклонения f от целой точки лежат в [-1/2, 1/2], и полуцелая точка ближе, если сумма |f| больше 1
+        let dev = (0..4).map(|c| (p[c] - 2*n*whole[c]).abs()).sum::<i128>();
+        let q = if dev <= 4*n { whole } else { half };
+        HNum{d:q.map(|x| x as i64)}
+    }
+    ///The method of the right division: returns the quotient q and the remainder r such that self = q·v + r and N(r) ≤ N(v)/2. The quotient is the nearest Hurwitz quaternion to self·v⁻¹. The divisor must not be zero
+    ///
+    ///Метод правого деления: возвращает частное q и остаток r, такие что self = q·v + r и N(r) ≤ N(v)/2. Частное - ближайший кватернион Гурвица к self·v⁻¹. Делитель не должен быть равен нулю
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// let (a, v) = (HNum::make(17, -5, 8, 3), HNum::make_from_doubled(3, 1, -1, 5));
+    /// let (q, r) = a.div_rem_right(v.clone());
+    /// assert!(q.mult_h(v.clone()).add_h(r.clone()) == a);
+    /// assert!(2*r.norm() <= v.norm());
+    /// //В отличие от кватернионов Липшица деление на 2 дает остаток 0
+    /// let (q, r) = HNum::make(1, 1, 1, 1).div_rem_right(HNum::make(2, 0, 0, 0));
+    /// assert!(q == HNum::make_from_doubled(1, 1, 1, 1) && r == HNum::make_zero());
+    /// ```
+    pub fn div_rem_right(&self, v:HNum) -> (HNum, HNum){
+        let n = v.norm_wide();
+        assert!(n != 0);
+        let q = HNum::round(mult_wide(self.d, conj(v.d)), n);
+        let r = self.add_h(-q.mult_h(v));
+        (q, r)
+    }
+    ///The method of the left division: returns the quotient q and the remainder r such that self = v·q + r and N(r) ≤ N(v)/2
+    ///
+    ///Метод левого деления: возвращает частное q и остаток r, такие что self = v·q + r и N(r) ≤ N(v)/2
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// let (a, v) = (HNum::make(17, -5, 8, 3), HNum::make_from_doubled(3, 1, -1, 5));
+    /// let (q, r) = a.div_rem_left(v.clone());
+    /// assert!(v.mult_h(q).add_h(r.clone()) == a);
+    /// assert!(2*r.norm() <= v.norm());
+    /// ```
+    pub fn div_rem_left(&self, v:HNum) -> (HNum, HNum){
+        let n = v.norm_wide();
+        assert!(n != 0);
+        let q = HNum::round(mult_wide(conj(v.d), self.d), n);
+        let r = self.add_h(-v.mult_h(q.clone()));
+        (q, r)
+    }
+    ///The function that returns the greatest common right divisor d: a = x·d, b = y·d, and every common right divisor of a and b is a right divisor of d. It is defined up to a unit on the left
+    ///
+    ///Функция, возвращающая наибольший общий правый делитель d: a = x·d, b = y·d, и любой общий правый делитель a и b является правым делителем d. Определен с точностью до единицы слева
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// let d = HNum::make(1, 1, 1, 0);
+    /// let a = HNum::make(2, -1, 0, 3).mult_h(d.clone());
+    /// let b = HNum::make(1, 0, 4, 0).mult_h(d.clone());
+    /// let g = HNum::gcd_right(a.clone(), b.clone());
+    /// assert_eq!(3, g.norm());
+    /// assert!(a.div_rem_right(g.clone()).1 == HNum::make_zero());
+    /// assert!(b.div_rem_right(g).1 == HNum::make_zero());
+    /// ```
+    pub fn gcd_right(a:HNum, b:HNum) -> HNum{
+        let (mut a, mut b) = (a, b);
+        while b.norm_wide() != 0 {
+            let (_, r) = a.div_rem_right(b.clone());
+            a = b;
+            b = r;
+        }
+        a
+    }
+    ///The function that returns the greatest common left divisor d: a = d·x, b = d·y. It is defined up to a unit on the right
+    ///
+    ///Функция, возвращающая наибольший общий левый делитель d: a = d·x, b = d·y. Определен с точностью до единицы справа
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// let d = HNum::make(1, 1, 1, 0);
+    /// let a = d.mult_h(HNum::make(2, -1, 0, 3));
+    /// let b = d.mult_h(HNum::make(1, 0, 4, 0));
+    /// let g = HNum::gcd_left(a.clone(), b.clone());
+    /// assert_eq!(3, g.norm());
+    /// assert!(a.div_rem_left(g.clone()).1 == HNum::make_zero());
+    /// assert!(b.div_rem_left(g).1 == HNum::make_zero());
+    /// ```
+    pub fn gcd_left(a:HNum, b:HNum) -> HNum{
+        let (mut a, mut b) = (a, b);
+        while b.norm_wide() != 0 {
+            let (_, r) = a.div_rem_left(b.clone());
+            a = b;
+            b = r;
+        }
+        a
+    }
+    //Некоторый кватернион Гурвица простой нормы p
+    fn prime_of_norm(p:u64) -> HNum{
+        if p == 2 {
+            return HNum::make(1, 1, 0, 0);
+        }
+        //1 + x² + y² = 0 (mod p)
+        let mut x = 0_u64;
+        let y = loop {
+            let a = (p - 1 - ((x as u128)*(x as u128)%(p as u128)) as u64)%p;
+            if a == 0 || arith::mod_pow(a, (p - 1)/2, p) == 1 {
+                break arith::sqrt_mod(a, p);
+            }
+            x += 1;
+        };
+        HNum::gcd_right(HNum::make(p as i64, 0, 0, 0), HNum::make(1, x as i64, y as i64, 0))
+    }
+    ///The method that factors the quaternion according to the factorization of its norm: returns the prime quaternions π1, π2, ... such that self = π1·π2·..., where N(π1) ≤ N(π2) ≤ ... are the prime factors of N(self). The units are absorbed by the last factor. For a unit the vector with this unit is returned. The norm must be nonzero and less than 2⁶⁰
+    ///
+    ///Метод, раскладывающий кватернион в соответствии с разложением его нормы: возвращает простые кватернионы π1, π2, ..., такие что self = π1·π2·..., где N(π1) ≤ N(π2) ≤ ... - простые множители N(self). Единицы поглощаются последним множителем. Для единицы возвращается вектор с этой единицей. Норма должна быть ненулевой и меньше 2⁶⁰
+    ///
+    /// # Example
+    ///```
+    /// use tmn::hurwitz::HNum;
+    /// let a = HNum::make(3, -7, 2, 5);
+    /// let ps = a.factor();
+    /// //Норма 87 = 3·29
+    /// let norms: Vec<i64> = ps.iter().map(|p| p.norm()).collect();
+    /// assert_eq!(vec![3, 29], norms);
+    /// let mut p = HNum::make(1, 0, 0, 0);
+    /// for f in ps {
+    ///     p = p.mult_h(f);
+    /// }
+    /// assert!(p == a);
+    /// //Делится на целое простое
+    /// let a = HNum::make(6, 3, 0, 9);
+    /// let ps = a.factor();
+    /// let norms: Vec<i64> = ps.iter().map(|p| p.norm()).collect();
+    /// assert_eq!(vec![2, 3, 3, 7], norms);
+    /// let mut p = HNum::make(1, 0, 0, 0);
+    /// for f in ps {
+    ///     p = p.mult_h(f);
+    /// }
+    /// assert!(p == a);
+    /// ```
+    pub fn factor(&self) -> Vec<HNum>{
+        assert!(self.norm() != 0);
+        let mut ret: Vec<HNum> = Vec::new();
+        let mut rest = self.clone();
+        for (p, e) in arith::factor(self.norm() as u64) {
+            for _ in 0..e {
+                let d = HNum::gcd_left(rest.clone(), HNum::make(p as i64, 0, 0, 0));
+                //Если rest делится на p, подходит любой простой делитель p
+                let pi = if d.norm_wide() == p as i128 { d } else { HNum::prime_of_norm(p) };
+                rest = rest.div_rem_left(pi.clone()).0;
+                ret.push(pi);
+            }
+        }
+        match ret.pop() {
+            Some(last) => ret.push(last.mult_h(rest)),
+            None => ret.push(rest)
+        }
+        ret
+    }
+}
+
+impl PartialEq for HNum {
+    fn eq(&self, other: &Self) -> bool {
+        self.d == other.d
+    }
+}
+
+impl Neg for HNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1)
+    }
+}
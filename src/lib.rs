@@ -1,15 +1,30 @@
 //!# TMN(Too Many Numbers)
 //!
-//! Library for working with complex numbers and quaternions
+//! Library for working with complex numbers, quaternions and octonions
 //!
-//! Библиотека для работы с комплексными числами и кватернионами
+//! Библиотека для работы с комплексными числами, кватернионами и октонионами
 use std::ops::{Add, Mul, Neg};
 use crate::complex::CNum;
-use crate::quaternion::QNum;
+use crate::quaternion::{QNum, UnitQNum};
+use crate::octonion::ONum;
 
 pub mod complex;
+pub mod split_complex;
+pub mod gaussian;
+pub mod eisenstein;
 pub mod quaternion;
+pub mod hurwitz;
+pub mod octonion;
+pub mod cayley_dickson;
 pub mod cassette;
+pub mod ahrs;
+pub mod dual_quaternion;
+pub mod dual;
+pub mod random;
+pub mod directional;
+pub mod spline;
+mod linalg;
+mod arith;
 
 ///Enum for convenient work with different types of numbers
 ///
@@ -17,7 +32,8 @@ pub mod cassette;
 pub enum Nums{
     Real(f32),
     Complex(CNum),
-    Quaternion(QNum)
+    Quaternion(QNum),
+    Octonion(ONum)
 }
 
 impl Nums{
@@ -54,7 +70,8 @@ impl Nums{
         match self{
             Nums::Real(re) => Nums::Real(*re),
             Nums::Complex(cnum) => Nums::Complex(cnum.conj()),
-            Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.conj())
+            Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.conj()),
+            Nums::Octonion(onum)=> Nums::Octonion(onum.conj())
         }
     }
     fn normalize(o:(f32, f32, f32)) -> (f32, f32, f32){//Нормализация вектора o
@@ -64,9 +81,9 @@ impl Nums{
         }
         (o.0/m, o.1/m, o.2/m)
     }
-    ///The method for rotating a number around the axis given by the vector 'o' by the angle 'ang' (Angle in degrees). The axis of rotation only affects the rotation of the quaternion.
+    ///The method for rotating a number around the axis given by the vector 'o' by the angle 'ang' (Angle in degrees). The axis of rotation only affects the rotation of the quaternion. Octonions are returned unchanged.
     ///
-    ///Метод для вращения числа вокруг оси, заданной вектором 'o' на угол 'ang' (Угол в градусах). Ось вращения влияет только на поворот кватерниона.
+    ///Метод для вращения числа вокруг оси, заданной вектором 'o' на угол 'ang' (Угол в градусах). Ось вращения влияет только на поворот кватерниона. Октонионы возвращаются без изменений.
     ///
     /// # Example
     ///
@@ -87,8 +104,6 @@ impl Nums{
     ///    _=>panic!("WrongType of Nums")
     /// }
     /// ```
-    ///
-
     pub fn rot(&self, ang:f32, o:(f32, f32, f32)) -> Self{
         let o = Nums::normalize(o);
         match self {
@@ -96,9 +111,12 @@ impl Nums{
             Nums::Complex(cnum)=>Nums::Complex(cnum.pow(ang/90_f32)),
             Nums::Quaternion(qnum)=> {
                 assert!(!o.0.is_nan());
-                let q = QNum::make_from_a(ang*std::f32::consts::PI/180_f32, o);
-                Nums::Quaternion(q.mult_q(qnum.clone()).mult_q(q.conj()))
-            }
+                let q = UnitQNum::make_from_a(ang*std::f32::consts::PI/180_f32, o);
+                let (r, i, j, k) = qnum.get();
+                let (i, j, k) = q.rotate_vector((i, j, k));
+                Nums::Quaternion(QNum::make_from_r(r, i, j, k))
+            },
+            Nums::Octonion(onum)=>Nums::Octonion(onum.clone())
         }
     }
     ///The method for setting values to specific coefficients
@@ -117,7 +135,8 @@ impl Nums{
         match self {
             Nums::Real(re)=>Nums::Real(*re),
             Nums::Complex(cnum)=>Nums::Complex(cnum.set(c, v)),
-            Nums::Quaternion(qnum)=>Nums::Quaternion(qnum.set(c, v))
+            Nums::Quaternion(qnum)=>Nums::Quaternion(qnum.set(c, v)),
+            Nums::Octonion(onum)=>Nums::Octonion(onum.set(c, v))
         }
     }
     ///The method for cloning the Nums element
@@ -132,11 +151,13 @@ impl Nums{
     /// let b = a.clone();
     /// assert!(a==b);
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn clone(&self)->Self{
         match self {
             Nums::Real(re) => Nums::Real(*re),
             Nums::Complex(cnum)=> Nums::Complex(cnum.clone()),
-            Nums::Quaternion(qnum) => Nums::Quaternion(qnum.clone())
+            Nums::Quaternion(qnum) => Nums::Quaternion(qnum.clone()),
+            Nums::Octonion(onum) => Nums::Octonion(onum.clone())
         }
     }
 }
@@ -161,6 +182,12 @@ impl PartialEq for Nums{
                     Nums::Quaternion(qnum1)=>qnum==qnum1,
                     _=>false
                 }
+            },
+            Nums::Octonion(onum)=>{
+                match other {
+                    Nums::Octonion(onum1)=>onum==onum1,
+                    _=>false
+                }
             }
         }
     }
@@ -185,27 +212,49 @@ impl Add for Nums{
     /// let c = a+b;
     /// assert!(Nums::Quaternion(QNum::make_from_r(1_f32,1_f32,1_f32,1_f32))==c);
     /// ```
+    ///```
+    /// use tmn::Nums;
+    /// use tmn::quaternion::QNum;
+    /// use tmn::octonion::ONum;
+    ///
+    /// let a = Nums::Quaternion(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32));
+    /// let b = Nums::Octonion(ONum::make_from_r([1_f32; 8]));
+    ///
+    /// let c = a+b;
+    /// assert!(Nums::Octonion(ONum::make_from_r([2_f32, 3_f32, 4_f32, 5_f32, 1_f32, 1_f32, 1_f32, 1_f32]))==c);
+    /// ```
     fn add(self, rhs: Self) -> Self::Output{
         match self {
             Nums::Real(re)=>{
                 match rhs {
                     Nums::Real(re1)=>Nums::Real(re + re1),
                     Nums::Complex(cnum) => Nums::Complex(cnum.add_r(re)),
-                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.add_r(re))
+                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.add_r(re)),
+                    Nums::Octonion(onum)=> Nums::Octonion(onum.add_r(re))
                 }
             }
             Nums::Complex(cnum) =>{
                 match rhs {
                     Nums::Real(re)=> Nums::Complex(cnum.add_r(re)),
                     Nums::Complex(cnum1) => Nums::Complex(cnum.add_c(cnum1)),
-                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.add_c(cnum.clone()))
+                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.add_c(cnum.clone())),
+                    Nums::Octonion(onum)=> Nums::Octonion(onum.add_c(cnum))
                 }
             },
             Nums::Quaternion(qnum)=>{
                 match rhs {
                     Nums::Real(re)=> Nums::Quaternion(qnum.add_r(re)),
                     Nums::Complex(cnum) => Nums::Quaternion(qnum.add_c(cnum)),
-                    Nums::Quaternion(qnum1)=> Nums::Quaternion(qnum.add_q(qnum1))
+                    Nums::Quaternion(qnum1)=> Nums::Quaternion(qnum.add_q(qnum1)),
+                    Nums::Octonion(onum)=> Nums::Octonion(onum.add_q(qnum))
+                }
+            },
+            Nums::Octonion(onum)=>{
+                match rhs {
+                    Nums::Real(re)=> Nums::Octonion(onum.add_r(re)),
+                    Nums::Complex(cnum) => Nums::Octonion(onum.add_c(cnum)),
+                    Nums::Quaternion(qnum)=> Nums::Octonion(onum.add_q(qnum)),
+                    Nums::Octonion(onum1)=> Nums::Octonion(onum.add_o(onum1))
                 }
             }
         }
@@ -231,27 +280,52 @@ impl Mul for Nums{
     /// let c = a*b;
     /// assert!(Nums::Quaternion(QNum::make_from_r(-8_f32, 172_f32, 303_f32, 29_f32))==c);
     /// ```
+    ///```
+    /// use tmn::{Nums, octonion};
+    /// use tmn::quaternion::QNum;
+    /// use tmn::octonion::ONum;
+    ///
+    /// //j·e4 = e6, e4·j = -e6
+    /// let a = Nums::Quaternion(QNum::make_from_r(0_f32, 0_f32, 1_f32, 0_f32));
+    /// let b = Nums::Octonion(ONum::make_zero().set(octonion::E4, 1_f32));
+    ///
+    /// let c = a.clone()*b.clone();
+    /// assert!(Nums::Octonion(ONum::make_zero().set(octonion::E6, 1_f32))==c);
+    /// let c = b*a;
+    /// assert!(Nums::Octonion(ONum::make_zero().set(octonion::E6, -1_f32))==c);
+    /// ```
     fn mul(self, rhs: Self) -> Self::Output {
         match self {
             Nums::Real(re)=>{
                 match rhs {
                     Nums::Real(re1)=>Nums::Real(re * re1),
                     Nums::Complex(cnum) => Nums::Complex(cnum.mult_r(re)),
-                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.mult_r(re))
+                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.mult_r(re)),
+                    Nums::Octonion(onum)=> Nums::Octonion(onum.mult_r(re))
                 }
             }
             Nums::Complex(cnum) =>{
                 match rhs {
                     Nums::Real(re)=> Nums::Complex(cnum.mult_r(re)),
                     Nums::Complex(cnum1) => Nums::Complex(cnum.mult_c(cnum1)),
-                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.mult_c(cnum.clone()))
+                    Nums::Quaternion(qnum)=> Nums::Quaternion(qnum.mult_c(cnum.clone())),
+                    Nums::Octonion(onum)=> Nums::Octonion(ONum::make_zero().add_c(cnum).mult_o(onum))
                 }
             },
             Nums::Quaternion(qnum)=>{
                 match rhs {
                     Nums::Real(re)=> Nums::Quaternion(qnum.mult_r(re)),
                     Nums::Complex(cnum) => Nums::Quaternion(qnum.mult_c(cnum)),
-                    Nums::Quaternion(qnum1)=> Nums::Quaternion(qnum.mult_q(qnum1))
+                    Nums::Quaternion(qnum1)=> Nums::Quaternion(qnum.mult_q(qnum1)),
+                    Nums::Octonion(onum)=> Nums::Octonion(ONum::make_zero().add_q(qnum).mult_o(onum))
+                }
+            },
+            Nums::Octonion(onum)=>{
+                match rhs {
+                    Nums::Real(re)=> Nums::Octonion(onum.mult_r(re)),
+                    Nums::Complex(cnum) => Nums::Octonion(onum.mult_c(cnum)),
+                    Nums::Quaternion(qnum)=> Nums::Octonion(onum.mult_q(qnum)),
+                    Nums::Octonion(onum1)=> Nums::Octonion(onum.mult_o(onum1))
                 }
             }
         }
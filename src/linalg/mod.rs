@@ -0,0 +1,149 @@
+//!Helper functions for small vectors and matrices
+//!
+//!Вспомогательные функции для небольших векторов и матриц
+
+pub(crate) fn m3_transpose(a:&[[f32; 3]; 3]) -> [[f32; 3]; 3]{
+    let mut ret = [[0_f32; 3]; 3];
+    for (r, row) in ret.iter_mut().enumerate(){
+        for (c, v) in row.iter_mut().enumerate(){
+            *v = a[c][r];
+        }
+    }
+    ret
+}
+
+pub(crate) fn m3_det(a:&[[f32; 3]; 3]) -> f32{
+    a[0][0]*(a[1][1]*a[2][2] - a[1][2]*a[2][1])
+        - a[0][1]*(a[1][0]*a[2][2] - a[1][2]*a[2][0])
+        + a[0][2]*(a[1][0]*a[2][1] - a[1][1]*a[2][0])
+}
+
+//Обратная матрица, None для вырожденной
+pub(crate) fn m3_inv(a:&[[f32; 3]; 3]) -> Option<[[f32; 3]; 3]>{
+    let d = m3_det(a);
+    if d == 0_f32 || !d.is_finite() {
+        return None;
+    }
+    let mut ret = [[0_f32; 3]; 3];
+    for (r, row) in ret.iter_mut().enumerate(){
+        for (c, v) in row.iter_mut().enumerate(){
+            //Алгебраическое дополнение элемента (c, r)
+            let (c1, c2) = ((c+1)%3, (c+2)%3);
+            let (r1, r2) = ((r+1)%3, (r+2)%3);
+            *v = (a[c1][r1]*a[c2][r2] - a[c1][r2]*a[c2][r1])/d;
+        }
+    }
+    Some(ret)
+}
+
+//Ближайшая ортогональная матрица (полярное разложение итерациями Ньютона)
+pub(crate) fn m3_orthonormalize(a:&[[f32; 3]; 3]) -> [[f32; 3]; 3]{
+    let mut m = *a;
+    for _ in 0..20 {
+        let it = match m3_inv(&m) {
+            Some(inv) => m3_transpose(&inv),
+            None => return m
+        };
+        let mut diff = 0_f32;
+        for r in 0..3 {
+            for c in 0..3 {
+                let v = 0.5_f32*(m[r][c] + it[r][c]);
+                diff = diff.max((v - m[r][c]).abs());
+                m[r][c] = v;
+            }
+        }
+        if diff < 0.0000001 {
+            break;
+        }
+    }
+    m
+}
+
+pub(crate) fn v_dot(a:(f32, f32, f32), b:(f32, f32, f32)) -> f32{ a.0*b.0 + a.1*b.1 + a.2*b.2 }
+
+pub(crate) fn v_modl(a:(f32, f32, f32)) -> f32{ v_dot(a, a).powf(0.5) }
+
+pub(crate) fn v_mult_r(a:(f32, f32, f32), v:f32) -> (f32, f32, f32){ (a.0*v, a.1*v, a.2*v) }
+
+//Нормализация вектора, None для нулевого вектора
+pub(crate) fn v_normalize(a:(f32, f32, f32)) -> Option<(f32, f32, f32)>{
+    let m = v_modl(a);
+    if m == 0_f32 || !m.is_finite() {
+        return None;
+    }
+    Some(v_mult_r(a, 1_f32/m))
+}
+
+pub(crate) fn v_cross(a:(f32, f32, f32), b:(f32, f32, f32)) -> (f32, f32, f32){
+    (a.1*b.2 - a.2*b.1, a.2*b.0 - a.0*b.2, a.0*b.1 - a.1*b.0)
+}
+
+pub(crate) fn v_add(a:(f32, f32, f32), b:(f32, f32, f32)) -> (f32, f32, f32){ (a.0+b.0, a.1+b.1, a.2+b.2) }
+
+//Собственные значения и векторы симметричной матрицы методом Якоби.
+//Возвращает значения и векторы (vecs[n] соответствует vals[n]), упорядоченные по убыванию значений
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn sym_eigen<const N: usize>(a:&[[f32; N]; N]) -> ([f32; N], [[f32; N]; N]){
+    let mut a = *a;
+    let mut v = [[0_f32; N]; N];
+    for (n, row) in v.iter_mut().enumerate(){
+        row[n] = 1_f32;
+    }
+    for _ in 0..50 {
+        let mut off = 0_f32;
+        let mut diag = 0_f32;
+        for p in 0..N {
+            diag += a[p][p]*a[p][p];
+            for q in p+1..N {
+                off += a[p][q]*a[p][q];
+            }
+        }
+        if off <= 1e-14_f32*diag || off == 0_f32 {
+            break;
+        }
+        for p in 0..N {
+            for q in p+1..N {
+                let apq = a[p][q];
+                if apq == 0_f32 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p])/(2_f32*apq);
+                let t = theta.signum()/(theta.abs() + (theta*theta + 1_f32).powf(0.5));
+                let c = 1_f32/(t*t + 1_f32).powf(0.5);
+                let s = t*c;
+                a[p][p] -= t*apq;
+                a[q][q] += t*apq;
+                a[p][q] = 0_f32;
+                a[q][p] = 0_f32;
+                for r in 0..N {
+                    if r != p && r != q {
+                        let (arp, arq) = (a[r][p], a[r][q]);
+                        a[r][p] = c*arp - s*arq;
+                        a[p][r] = a[r][p];
+                        a[r][q] = s*arp + c*arq;
+                        a[q][r] = a[r][q];
+                    }
+                }
+                for row in v.iter_mut() {
+                    let (vrp, vrq) = (row[p], row[q]);
+                    row[p] = c*vrp - s*vrq;
+                    row[q] = s*vrp + c*vrq;
+                }
+            }
+        }
+    }
+    let mut order = [0_usize; N];
+    for (n, o) in order.iter_mut().enumerate(){
+        *o = n;
+    }
+    order.sort_by(|x, y| a[*y][*y].total_cmp(&a[*x][*x]));
+    let mut vals = [0_f32; N];
+    let mut vecs = [[0_f32; N]; N];
+    for (n, o) in order.iter().enumerate(){
+        vals[n] = a[*o][*o];
+        for r in 0..N {
+            vecs[n][r] = v[r][*o];
+        }
+    }
+    (vals, vecs)
+}
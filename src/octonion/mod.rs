@@ -0,0 +1,303 @@
+//!Octonions
+use std::ops::Neg;
+use crate::cassette;
+use crate::complex::CNum;
+use crate::quaternion::QNum;
+
+///The structure storing the octonion as a pair of quaternions (a, b) = a + b·e4 (the Cayley-Dickson construction)
+///
+///Структура, хранящая октонион в виде пары кватернионов (a, b) = a + b·e4 (построение Кэли-Диксона)
+pub struct ONum{a:QNum, b:QNum}
+pub const E0:u8 = 1;
+pub const E1:u8 = 2;
+pub const E2:u8 = 4;
+pub const E3:u8 = 8;
+pub const E4:u8 = 16;
+pub const E5:u8 = 32;
+pub const E6:u8 = 64;
+pub const E7:u8 = 128;
+
+impl ONum {
+    ///The function for creating an octonion with zero coefficients
+    ///
+    ///Функция для создания октониона с нулевыми коэффициентами
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_zero();
+    /// assert_eq!([0_f32; 8], a.get());
+    /// ```
+    pub fn make_zero() -> Self{ ONum{a:QNum::make_zero(), b:QNum::make_zero()} }
+    ///The function that creates an octonion from 8 real coefficients e0..e7
+    ///
+    ///Функция, создающая октонион из 8 действительных коэффициентов e0..e7
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32]);
+    /// assert_eq!([1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32], a.get());
+    /// ```
+    pub fn make_from_r(e:[f32; 8]) -> Self{
+        ONum{
+            a:QNum::make_from_r(e[0], e[1], e[2], e[3]),
+            b:QNum::make_from_r(e[4], e[5], e[6], e[7])
+        }
+    }
+    ///The function that creates an octonion a + b·e4 from 2 quaternions
+    ///
+    ///Функция, создающая октонион a + b·e4 из 2 кватернионов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// use tmn::quaternion::QNum;
+    /// let a = ONum::make_from_q(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32), QNum::make_from_r(5_f32, 6_f32, 7_f32, 8_f32));
+    /// assert_eq!([1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32], a.get());
+    /// ```
+    pub fn make_from_q(a:QNum, b:QNum) -> Self{ ONum{a, b} }
+    ///The method for cloning an octonion
+    ///
+    ///Метод для клонирования октониона
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32; 8]);
+    /// assert!(a.clone() == a);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> ONum{ ONum{a:self.a.clone(), b:self.b.clone()} }
+    ///The method that returns the coefficients e0..e7 of the octonion
+    ///
+    ///Метод, возвращающий коэффициенты e0..e7 октониона
+    pub fn get(&self) -> [f32; 8]{
+        let (a0, a1, a2, a3) = self.a.get();
+        let (b0, b1, b2, b3) = self.b.get();
+        [a0, a1, a2, a3, b0, b1, b2, b3]
+    }
+    ///The method that returns the pair of quaternions (a, b) of the octonion a + b·e4
+    ///
+    ///Метод, возвращающий пару кватернионов (a, b) октониона a + b·e4
+    pub fn get_q(&self) -> (QNum, QNum){ (self.a.clone(), self.b.clone()) }
+    ///The method that returns the conjugate octonion: (a, b)* = (a*, -b)
+    ///
+    ///Метод, возвращающий сопряженный октонион: (a, b)* = (a*, -b)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32]);
+    /// assert_eq!([1_f32, -2_f32, -3_f32, -4_f32, -5_f32, -6_f32, -7_f32, -8_f32], a.conj().get());
+    /// ```
+    pub fn conj(&self) -> ONum{ ONum{a:self.a.conj(), b:-self.b.clone()} }
+    ///The method that returns the octonion norm (the sum of squares of the coefficients)
+    ///
+    ///Метод, возвращающий норму октониона (сумму квадратов коэффициентов)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32; 8]);
+    /// assert_eq!(8_f32, a.norm());
+    /// ```
+    pub fn norm(&self) -> f32{ self.a.norm() + self.b.norm() }
+    ///The method that returns the octonion module
+    ///
+    ///Метод, возвращающий модуль октониона
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([2_f32; 8]);
+    /// assert!((a.modl()-32_f32.powf(0.5)).abs() < 0.000001);
+    /// ```
+    pub fn modl(&self) -> f32{ self.norm().powf(0.5) }
+    ///The method that returns the sum of an octonion and a real number
+    ///
+    ///Метод, возвращающий сумму октониона и действительного числа
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32; 8]).add_r(2_f32);
+    /// assert_eq!([3_f32, 1_f32, 1_f32, 1_f32, 1_f32, 1_f32, 1_f32, 1_f32], a.get());
+    /// ```
+    pub fn add_r(&self, v:f32) -> ONum{ ONum{a:self.a.add_r(v), b:self.b.clone()} }
+    ///The method that returns the sum of an octonion and a complex number
+    ///
+    ///Метод, возвращающий сумму октониона и комплексного числа
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// use tmn::complex::CNum;
+    /// let a = ONum::make_from_r([1_f32; 8]).add_c(CNum::make(2_f32, 3_f32));
+    /// assert_eq!([3_f32, 4_f32, 1_f32, 1_f32, 1_f32, 1_f32, 1_f32, 1_f32], a.get());
+    /// ```
+    pub fn add_c(&self, v:CNum) -> ONum{ ONum{a:self.a.add_c(v), b:self.b.clone()} }
+    ///The method that returns the sum of an octonion and a quaternion
+    ///
+    ///Метод, возвращающий сумму октониона и кватерниона
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// use tmn::quaternion::QNum;
+    /// let a = ONum::make_from_r([1_f32; 8]).add_q(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32));
+    /// assert_eq!([2_f32, 3_f32, 4_f32, 5_f32, 1_f32, 1_f32, 1_f32, 1_f32], a.get());
+    /// ```
+    pub fn add_q(&self, v:QNum) -> ONum{ ONum{a:self.a.add_q(v), b:self.b.clone()} }
+    ///The method that returns the sum of octonions
+    ///
+    ///Метод, возвращающий сумму октонионов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32; 8]).add_o(ONum::make_from_r([2_f32; 8]));
+    /// assert_eq!([3_f32; 8], a.get());
+    /// ```
+    pub fn add_o(&self, v:ONum) -> ONum{ ONum{a:self.a.add_q(v.a), b:self.b.add_q(v.b)} }
+    ///The method that returns the product of an octonion and a real number
+    ///
+    ///Метод, возвращающий произведение октониона и действительного числа
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_from_r([1_f32; 8]).mult_r(3_f32);
+    /// assert_eq!([3_f32; 8], a.get());
+    /// ```
+    pub fn mult_r(&self, v:f32) -> ONum{ ONum{a:self.a.mult_r(v), b:self.b.mult_r(v)} }
+    ///The method that returns the product of an octonion and a complex number (the complex number is on the right)
+    ///
+    ///Метод, возвращающий произведение октониона и комплексного числа (комплексное число справа)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// use tmn::complex::CNum;
+    /// use tmn::quaternion::QNum;
+    /// let a = ONum::make_from_r([1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32]);
+    /// let c = CNum::make(2_f32, -1_f32);
+    /// let e = a.mult_o(ONum::make_from_q(QNum::make_from_r(2_f32, -1_f32, 0_f32, 0_f32), QNum::make_zero()));
+    /// assert!(a.mult_c(c) == e);
+    /// ```
+    pub fn mult_c(&self, v:CNum) -> ONum{ self.mult_q(QNum::make_from_c(v, CNum::make_zero())) }
+    ///The method that returns the product of an octonion and a quaternion (the quaternion is on the right)
+    ///
+    ///Метод, возвращающий произведение октониона и кватерниона (кватернион справа)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// use tmn::quaternion::QNum;
+    /// let a = ONum::make_from_q(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32), QNum::make_zero());
+    /// let q = QNum::make_from_r(-1_f32, 0_f32, 2_f32, 1_f32);
+    /// //На подалгебре кватернионов произведение совпадает с кватернионным
+    /// let (x, y) = a.mult_q(q.clone()).get_q();
+    /// assert!(x == QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32).mult_q(q));
+    /// assert!(y == QNum::make_zero());
+    /// ```
+    pub fn mult_q(&self, v:QNum) -> ONum{ self.mult_o(ONum{a:v, b:QNum::make_zero()}) }
+    ///The method that returns the product of octonions by the Cayley-Dickson formula (a, b)(c, d) = (ac - d*b, da + bc*). The product is neither commutative nor associative, but it is alternative and the norm is multiplicative
+    ///
+    ///Метод, возвращающий произведение октонионов по формуле Кэли-Диксона (a, b)(c, d) = (ac - d*b, da + bc*). Произведение не коммутативно и не ассоциативно, но альтернативно, а норма мультипликативна
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::{self, ONum};
+    /// let e = |c:u8| ONum::make_zero().set(c, 1_f32);
+    /// //e1·e2 = e3, e1·e4 = e5
+    /// assert!(e(octonion::E1).mult_o(e(octonion::E2)) == e(octonion::E3));
+    /// assert!(e(octonion::E1).mult_o(e(octonion::E4)) == e(octonion::E5));
+    /// //Неассоциативность: (e1·e2)·e4 = -e1·(e2·e4)
+    /// let l = e(octonion::E1).mult_o(e(octonion::E2)).mult_o(e(octonion::E4));
+    /// let r = e(octonion::E1).mult_o(e(octonion::E2).mult_o(e(octonion::E4)));
+    /// assert!(l == -r.clone());
+    /// assert!(l != r);
+    /// ```
+    /// ```
+    /// use tmn::octonion::ONum;
+    /// let x = ONum::make_from_r([1_f32, -2_f32, 0.5_f32, 3_f32, -1_f32, 2_f32, 1_f32, -0.5_f32]);
+    /// let y = ONum::make_from_r([0.5_f32, 1_f32, -1_f32, 2_f32, 3_f32, -2_f32, 0.5_f32, 1_f32]);
+    /// let z = ONum::make_from_r([-1_f32, 0.5_f32, 2_f32, 1_f32, -0.5_f32, 1_f32, -2_f32, 3_f32]);
+    /// //Неассоциативность для произвольных октонионов
+    /// let d = x.mult_o(y.clone()).mult_o(z.clone()).add_o(-x.mult_o(y.mult_o(z.clone())));
+    /// assert!(d.norm() > 1_f32);
+    /// //Альтернативность: (x·x)·y = x·(x·y), (y·x)·x = y·(x·x)
+    /// let d = x.mult_o(x.clone()).mult_o(y.clone()).add_o(-x.mult_o(x.mult_o(y.clone())));
+    /// assert!(d.norm() < 0.0001);
+    /// let d = y.mult_o(x.clone()).mult_o(x.clone()).add_o(-y.mult_o(x.mult_o(x.clone())));
+    /// assert!(d.norm() < 0.0001);
+    /// //Мультипликативность нормы
+    /// assert!((x.mult_o(y.clone()).norm()-x.norm()*y.norm()).abs() < 0.001);
+    /// ```
+    pub fn mult_o(&self, v:ONum) -> ONum{
+        let (a, b) = (&self.a, &self.b);
+        let (c, d) = (v.a, v.b);
+        ONum{
+            a:a.mult_q(c.clone()).add_q(-d.conj().mult_q(b.clone())),
+            b:d.mult_q(a.clone()).add_q(b.mult_q(c.conj()))
+        }
+    }
+    ///The method that returns the inverse octonion
+    ///
+    ///Метод, возвращающий обратный октонион
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let x = ONum::make_from_r([1_f32, -2_f32, 0.5_f32, 3_f32, -1_f32, 2_f32, 1_f32, -0.5_f32]);
+    /// let p = x.mult_o(x.inv()).get();
+    /// assert!((p[0]-1_f32).abs() < 0.000001);
+    /// for c in &p[1..] {
+    ///     assert!(c.abs() < 0.000001);
+    /// }
+    /// ```
+    pub fn inv(&self) -> ONum{ self.conj().mult_r(1_f32/self.norm()) }
+    ///The method for setting values to specific coefficients
+    ///
+    ///Метод для установки значений в конкретные коэффициенты
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion;
+    /// use tmn::octonion::ONum;
+    /// let a = ONum::make_zero().set(octonion::E0|octonion::E5|octonion::E7, 3_f32);
+    /// assert_eq!([3_f32, 0_f32, 0_f32, 0_f32, 0_f32, 3_f32, 0_f32, 3_f32], a.get());
+    /// ```
+    pub fn set(&self, c:u8, v:f32) -> Self{
+        let mut e = self.get();
+        for (n, x) in e.iter_mut().enumerate() {
+            if cassette::cassette::eq(c, n as u8){
+                *x = v;
+            }
+        }
+        ONum::make_from_r(e)
+    }
+}
+
+impl PartialEq for ONum{
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+}
+impl Neg for ONum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::octonion::ONum;
+    /// let a = -ONum::make_from_r([1_f32; 8]);
+    /// assert_eq!([-1_f32; 8], a.get());
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1_f32)
+    }
+}
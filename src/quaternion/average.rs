@@ -0,0 +1,99 @@
+//!Averaging of rotation quaternions
+use super::QNum;
+use crate::linalg;
+
+impl QNum {
+    //Взвешенное среднеквадратичное угловое отклонение кватернионов 'qs' от 'mean'
+    fn dispersion(mean:&QNum, qs:&[QNum], ws:&[f32]) -> f32{
+        let mut s = 0_f32;
+        let mut sw = 0_f32;
+        for (q, w) in qs.iter().zip(ws) {
+            let ang = 2_f32*mean.dot(q.clone()).abs().min(1_f32).acos();
+            s += w*ang*ang;
+            sw += w;
+        }
+        (s/sw).powf(0.5)
+    }
+    fn check_weights(qs:&[QNum], ws:&[f32]) -> Vec<f32>{
+        assert!(!qs.is_empty());
+        if ws.is_empty() {
+            return vec![1_f32; qs.len()];
+        }
+        assert_eq!(qs.len(), ws.len());
+        //Нулевая сумма весов дала бы NaN в среднем и разбросе
+        assert!(ws.iter().sum::<f32>() > 0_f32);
+        ws.to_vec()
+    }
+    ///The function that returns the weighted average of the rotation quaternions 'qs' with the weights 'ws' (an empty slice of weights means equal weights) and the dispersion, the weighted root mean square angle (in radians) between the average and the quaternions. Markley's method is used: the average is the eigenvector of the largest eigenvalue of the matrix sum(w·q·qT), so 'q' and '-q' are treated as the same rotation. The slice 'qs' must not be empty, a non-empty slice 'ws' must have the same length, and the sum of the weights must be positive
+    ///
+    ///Функция, возвращающая взвешенное среднее кватернионов поворота 'qs' с весами 'ws' (пустой срез весов означает равные веса) и разброс - взвешенный среднеквадратичный угол (в радианах) между средним и кватернионами. Используется метод Маркли: среднее является собственным вектором наибольшего собственного значения матрицы sum(w·q·qT), поэтому 'q' и '-q' считаются одним поворотом. Срез 'qs' не должен быть пустым, непустой срез 'ws' должен иметь ту же длину, а сумма весов должна быть положительной
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// //Знак кватерниона не влияет на результат
+    /// let qs = [QNum::make_from_a(0.1_f32, z), -QNum::make_from_a(0.3_f32, z), QNum::make_from_a(0.5_f32, z)];
+    /// let (mean, disp) = QNum::average(&qs, &[]);
+    /// assert!(mean.dot(QNum::make_from_a(0.3_f32, z)).abs() > 0.99999_f32);
+    /// assert!((disp - (0.08_f32/3_f32).powf(0.5)).abs() < 0.0005);
+    /// //Веса
+    /// let (mean, _) = QNum::average(&qs[0..2], &[3_f32, 1_f32]);
+    /// assert!(mean.dot(QNum::make_from_a(0.15_f32, z)).abs() > 0.99999_f32);
+    /// ```
+    pub fn average(qs:&[QNum], ws:&[f32]) -> (QNum, f32){
+        let ws = QNum::check_weights(qs, ws);
+        let mut m = [[0_f32; 4]; 4];
+        for (q, w) in qs.iter().zip(&ws) {
+            let v = [q.r, q.i, q.j, q.k];
+            for r in 0..4 {
+                for c in 0..4 {
+                    m[r][c] += w*v[r]*v[c];
+                }
+            }
+        }
+        let (_, vecs) = linalg::sym_eigen(&m);
+        let v = vecs[0];
+        let mean = QNum::make_from_r(v[0], v[1], v[2], v[3]).normalize();
+        let mean = if mean.r < 0_f32 { -mean } else { mean };
+        let disp = QNum::dispersion(&mean, qs, &ws);
+        (mean, disp)
+    }
+    ///The function that returns the weighted geodesic (Karcher) mean of the rotation quaternions 'qs' with the weights 'ws' (an empty slice of weights means equal weights) and the dispersion, the weighted root mean square angle (in radians) between the mean and the quaternions. The mean minimizes the sum of squared rotation angles and is found iteratively starting from the result of 'average'. The slice 'qs' must not be empty, a non-empty slice 'ws' must have the same length, and the sum of the weights must be positive
+    ///
+    ///Функция, возвращающая взвешенное геодезическое среднее (среднее Кархера) кватернионов поворота 'qs' с весами 'ws' (пустой срез весов означает равные веса) и разброс - взвешенный среднеквадратичный угол (в радианах) между средним и кватернионами. Среднее минимизирует сумму квадратов углов поворота и находится итерационно, начиная с результата 'average'. Срез 'qs' не должен быть пустым, непустой срез 'ws' должен иметь ту же длину, а сумма весов должна быть положительной
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let qs = [
+    ///     QNum::make_from_a(0.2_f32, (1_f32, 0_f32, 0_f32)),
+    ///     QNum::make_from_a(0.2_f32, (-1_f32, 0_f32, 0_f32)),
+    ///     QNum::make_from_a(0.2_f32, (0_f32, 1_f32, 0_f32)),
+    ///     -QNum::make_from_a(0.2_f32, (0_f32, -1_f32, 0_f32))
+    /// ];
+    /// let (mean, disp) = QNum::karcher_mean(&qs, &[]);
+    /// assert!(mean.dot(QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32)) > 0.999999_f32);
+    /// assert!((disp - 0.2_f32).abs() < 0.0001);
+    /// ```
+    pub fn karcher_mean(qs:&[QNum], ws:&[f32]) -> (QNum, f32){
+        let (mut mean, _) = QNum::average(qs, ws);
+        let ws = QNum::check_weights(qs, ws);
+        let sw: f32 = ws.iter().sum();
+        for _ in 0..100 {
+            let inv = mean.conj();
+            let mut d = (0_f32, 0_f32, 0_f32);
+            for (q, w) in qs.iter().zip(&ws) {
+                let v = inv.mult_q(q.clone()).to_rotation_vector();
+                d = linalg::v_add(d, linalg::v_mult_r(v, w/sw));
+            }
+            mean = mean.mult_q(QNum::from_rotation_vector(d)).normalize();
+            if linalg::v_modl(d) < 0.0000001_f32 {
+                break;
+            }
+        }
+        let mean = if mean.r < 0_f32 { -mean } else { mean };
+        let disp = QNum::dispersion(&mean, qs, &ws);
+        (mean, disp)
+    }
+}
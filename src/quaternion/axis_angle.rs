@@ -0,0 +1,64 @@
+//!Axis-angle and rotation vector representations
+use super::QNum;
+use crate::linalg;
+
+impl QNum {
+    ///The method that returns the angle (in radians, from 0 to 2*PI) and the unit axis of the rotation given by the quaternion. It is the inverse of 'make_from_a'. For the identity rotation the axis (1, 0, 0) is returned
+    ///
+    ///Метод, возвращающий угол (в радианах, от 0 до 2*PI) и единичную ось поворота, заданного кватернионом. Является обратным к 'make_from_a'. Для тождественного поворота возвращается ось (1, 0, 0)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(2_f32, (0_f32, 3_f32, 4_f32));
+    /// let (ang, (x, y, z)) = q.to_axis_angle();
+    /// assert!((ang-2_f32).abs() < 0.000001);
+    /// assert!(x.abs() < 0.000001 && (y-0.6_f32).abs() < 0.000001 && (z-0.8_f32).abs() < 0.000001);
+    /// let (ang, axis) = QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32).to_axis_angle();
+    /// assert_eq!((0_f32, (1_f32, 0_f32, 0_f32)), (ang, axis));
+    /// ```
+    pub fn to_axis_angle(&self) -> (f32, (f32, f32, f32)){
+        let v = (self.i, self.j, self.k);
+        match linalg::v_normalize(v) {
+            Some(axis) => (2_f32*linalg::v_modl(v).atan2(self.r), axis),
+            None => (0_f32, (1_f32, 0_f32, 0_f32))
+        }
+    }
+    ///The function that creates a rotation quaternion from the rotation vector: the direction of the vector is the axis, the length is the angle in radians
+    ///
+    ///Функция, создающая кватернион поворота из вектора поворота: направление вектора задает ось, длина - угол в радианах
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::from_rotation_vector((0_f32, 0_f32, std::f32::consts::PI/2_f32));
+    /// let e = QNum::make_from_a(std::f32::consts::PI/2_f32, (0_f32, 0_f32, 1_f32));
+    /// assert!(q.dot(e) > 0.9999999_f32);
+    /// //Малые углы
+    /// let (r, i, j, k) = QNum::from_rotation_vector((0.000002_f32, 0_f32, 0_f32)).get();
+    /// assert_eq!((1_f32, 0.000001_f32, 0_f32, 0_f32), (r, i, j, k));
+    /// ```
+    pub fn from_rotation_vector(v:(f32, f32, f32)) -> QNum{
+        QNum{r:0_f32, i:v.0/2_f32, j:v.1/2_f32, k:v.2/2_f32}.exp()
+    }
+    ///The method that returns the rotation vector of the rotation given by the quaternion. The angle is taken from 0 to PI, so 'q' and '-q' give the same vector
+    ///
+    ///Метод, возвращающий вектор поворота для поворота, заданного кватернионом. Угол берется от 0 до PI, поэтому 'q' и '-q' дают один и тот же вектор
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(-2.5_f32, (1_f32, 2_f32, 2_f32));
+    /// let (x, y, z) = q.to_rotation_vector();
+    /// assert!((x+2.5_f32/3_f32).abs() < 0.00001 && (y+5_f32/3_f32).abs() < 0.00001 && (z+5_f32/3_f32).abs() < 0.00001);
+    /// let (x1, y1, z1) = (-q).to_rotation_vector();
+    /// assert_eq!((x, y, z), (x1, y1, z1));
+    /// let (x, y, z) = QNum::make_from_a(0.000002_f32, (0_f32, 1_f32, 0_f32)).to_rotation_vector();
+    /// assert_eq!((0_f32, 0.000002_f32, 0_f32), (x, y, z));
+    /// ```
+    pub fn to_rotation_vector(&self) -> (f32, f32, f32){
+        let q = if self.r < 0_f32 { -self.normalize() } else { self.normalize() };
+        let l = q.ln();
+        (2_f32*l.i, 2_f32*l.j, 2_f32*l.k)
+    }
+}
@@ -0,0 +1,58 @@
+//!Sign canonicalisation of rotation quaternions
+use super::QNum;
+
+impl QNum {
+    ///The method that returns the canonical sign of the quaternion: 'q' or '-q' with a positive real part. If the real part is zero, the first non-zero imaginary coefficient is made positive
+    ///
+    ///Метод, возвращающий канонический знак кватерниона: 'q' или '-q' с положительной действительной частью. Если действительная часть равна нулю, положительным делается первый ненулевой мнимый коэффициент
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// assert_eq!((0.5_f32, -0.5_f32, 0.5_f32, 0.5_f32), QNum::make_from_r(-0.5_f32, 0.5_f32, -0.5_f32, -0.5_f32).canonicalize().get());
+    /// assert_eq!((0_f32, 0_f32, 1_f32, -1_f32), QNum::make_from_r(0_f32, 0_f32, -1_f32, 1_f32).canonicalize().get());
+    /// ```
+    pub fn canonicalize(&self) -> QNum{
+        let first = [self.r, self.i, self.j, self.k].into_iter().find(|v| *v != 0_f32).unwrap_or(0_f32);
+        if first < 0_f32 { -self.clone() } else { self.clone() }
+    }
+    ///The method that returns 'q' or '-q', whichever lies in the same hemisphere as the quaternion 'reference' (has a non-negative dot product with it)
+    ///
+    ///Метод, возвращающий 'q' или '-q' - тот, который лежит в одной полусфере с кватернионом 'reference' (имеет с ним неотрицательное скалярное произведение)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let reference = QNum::make_from_r(0_f32, 0_f32, 0_f32, 1_f32);
+    /// let q = QNum::make_from_r(0.6_f32, 0_f32, 0_f32, -0.8_f32);
+    /// assert_eq!((-0.6_f32, -0_f32, -0_f32, 0.8_f32), q.canonicalize_to(reference).get());
+    /// ```
+    pub fn canonicalize_to(&self, reference:QNum) -> QNum{
+        if self.dot(reference) < 0_f32 { -self.clone() } else { self.clone() }
+    }
+    ///The function that enforces the sign continuity of the sequence of quaternions 'qs' in place: every quaternion is replaced by 'q' or '-q', whichever is closer to the previous one. The first quaternion is not changed
+    ///
+    ///Функция, обеспечивающая на месте непрерывность знака последовательности кватернионов 'qs': каждый кватернион заменяется на 'q' или '-q' - тот, который ближе к предыдущему. Первый кватернион не изменяется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// let mut qs: Vec<QNum> = (0..8).map(|n| {
+    ///     let q = QNum::make_from_a(n as f32, z);
+    ///     if n%3 == 1 { -q } else { q }
+    /// }).collect();
+    /// QNum::unwrap(&mut qs);
+    /// for n in 1..8 {
+    ///     assert!(qs[n].dot(qs[n-1].clone()) > 0_f32);
+    ///     assert!(qs[n].dot(QNum::make_from_a(n as f32, z)).abs() > 0.99999_f32);
+    /// }
+    /// ```
+    pub fn unwrap(qs:&mut [QNum]){
+        for n in 1..qs.len() {
+            if qs[n].dot(qs[n-1].clone()) < 0_f32 {
+                qs[n] = -qs[n].clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//!Euler and Tait-Bryan angles
+use super::QNum;
+use std::f32::consts::PI;
+
+///The sequence of rotation axes. The first six sequences are Tait-Bryan angles, the last six are proper Euler angles
+///
+///Последовательность осей вращения. Первые шесть последовательностей - углы Тейта-Брайана, последние шесть - собственные углы Эйлера
+pub enum EulerAxes{
+    Xyz, Xzy, Yxz, Yzx, Zxy, Zyx,
+    Xyx, Xzx, Yxy, Yzy, Zxz, Zyz
+}
+
+///The sequence of rotations: intrinsic (around the axes of the rotating frame) or extrinsic (around the fixed axes)
+///
+///Последовательность поворотов: внутренняя (вокруг осей вращающейся системы координат) или внешняя (вокруг неподвижных осей)
+pub enum EulerSeq{
+    Intrinsic(EulerAxes),
+    Extrinsic(EulerAxes)
+}
+
+impl EulerAxes {
+    fn indices(&self) -> (usize, usize, usize){
+        match self {
+            EulerAxes::Xyz => (0, 1, 2),
+            EulerAxes::Xzy => (0, 2, 1),
+            EulerAxes::Yxz => (1, 0, 2),
+            EulerAxes::Yzx => (1, 2, 0),
+            EulerAxes::Zxy => (2, 0, 1),
+            EulerAxes::Zyx => (2, 1, 0),
+            EulerAxes::Xyx => (0, 1, 0),
+            EulerAxes::Xzx => (0, 2, 0),
+            EulerAxes::Yxy => (1, 0, 1),
+            EulerAxes::Yzy => (1, 2, 1),
+            EulerAxes::Zxz => (2, 0, 2),
+            EulerAxes::Zyz => (2, 1, 2)
+        }
+    }
+}
+
+impl QNum {
+    fn make_elementary(axis:usize, ang:f32) -> QNum{
+        let s = (ang/2_f32).sin();
+        QNum{
+            r:(ang/2_f32).cos(),
+            i:if axis == 0 { s } else { 0_f32 },
+            j:if axis == 1 { s } else { 0_f32 },
+            k:if axis == 2 { s } else { 0_f32 }
+        }
+    }
+    fn vec_component(&self, axis:usize) -> f32{
+        match axis {
+            0 => self.i,
+            1 => self.j,
+            _ => self.k
+        }
+    }
+    ///The function that creates a rotation quaternion from the angles 'a', 'b', 'c' (in radians) for the rotations around the axes of the sequence 'seq'. For intrinsic rotations the result is q(a)q(b)q(c), for extrinsic ones - q(c)q(b)q(a)
+    ///
+    ///Функция, создающая кватернион поворота из углов 'a', 'b', 'c' (в радианах) для поворотов вокруг осей последовательности 'seq'. Для внутренних поворотов результат равен q(a)q(b)q(c), для внешних - q(c)q(b)q(a)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::{QNum, EulerSeq, EulerAxes};
+    /// //Курс 90 градусов в последовательности ZYX (рыскание, тангаж, крен)
+    /// let q = QNum::from_euler(EulerSeq::Intrinsic(EulerAxes::Zyx), std::f32::consts::PI/2_f32, 0_f32, 0_f32);
+    /// let e = QNum::make_from_a(std::f32::consts::PI/2_f32, (0_f32, 0_f32, 1_f32));
+    /// assert!(q == e);
+    /// //Внешние повороты XYZ совпадают с внутренними ZYX в обратном порядке углов
+    /// let a = QNum::from_euler(EulerSeq::Extrinsic(EulerAxes::Xyz), 0.1_f32, 0.2_f32, 0.3_f32);
+    /// let b = QNum::from_euler(EulerSeq::Intrinsic(EulerAxes::Zyx), 0.3_f32, 0.2_f32, 0.1_f32);
+    /// assert!(a == b);
+    /// ```
+    pub fn from_euler(seq:EulerSeq, a:f32, b:f32, c:f32) -> QNum{
+        match seq {
+            EulerSeq::Intrinsic(axes) => {
+                let (i, j, k) = axes.indices();
+                QNum::make_elementary(i, a).mult_q(QNum::make_elementary(j, b)).mult_q(QNum::make_elementary(k, c))
+            },
+            EulerSeq::Extrinsic(axes) => {
+                let (i, j, k) = axes.indices();
+                QNum::make_elementary(k, c).mult_q(QNum::make_elementary(j, b)).mult_q(QNum::make_elementary(i, a))
+            }
+        }
+    }
+    ///The method that returns the angles (in radians) of the rotation given by the quaternion in the sequence 'seq'. The first and third angles lie in [-PI, PI], the second one - in [-PI/2, PI/2] for Tait-Bryan angles and in [0, PI] for proper Euler angles. In the gimbal lock the third angle is set to zero and the whole rotation is assigned to the first one
+    ///
+    ///Метод, возвращающий углы (в радианах) поворота, заданного кватернионом, в последовательности 'seq'. Первый и третий углы лежат в [-PI, PI], второй - в [-PI/2, PI/2] для углов Тейта-Брайана и в [0, PI] для собственных углов Эйлера. При складывании рамок третий угол полагается равным нулю, и весь поворот приходится на первый угол
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::{QNum, EulerSeq, EulerAxes};
+    /// let q = QNum::from_euler(EulerSeq::Intrinsic(EulerAxes::Zyx), 0.5_f32, -0.3_f32, 1.2_f32);
+    /// let (a, b, c) = q.to_euler(EulerSeq::Intrinsic(EulerAxes::Zyx));
+    /// assert!((a-0.5_f32).abs() < 0.00001 && (b+0.3_f32).abs() < 0.00001 && (c-1.2_f32).abs() < 0.00001);
+    /// ```
+    /// ```
+    /// use tmn::quaternion::{QNum, EulerSeq, EulerAxes};
+    /// //Прямое и обратное преобразование для всех 12 последовательностей
+    /// let seqs = || [EulerAxes::Xyz, EulerAxes::Xzy, EulerAxes::Yxz, EulerAxes::Yzx, EulerAxes::Zxy, EulerAxes::Zyx,
+    ///     EulerAxes::Xyx, EulerAxes::Xzx, EulerAxes::Yxy, EulerAxes::Yzy, EulerAxes::Zxz, EulerAxes::Zyz];
+    /// let q = QNum::make_from_a(2_f32, (0.48_f32, -0.6_f32, 0.64_f32));
+    /// for (a1, a2) in seqs().into_iter().zip(seqs()) {
+    ///     let (a, b, c) = q.to_euler(EulerSeq::Intrinsic(a1));
+    ///     assert!(QNum::from_euler(EulerSeq::Intrinsic(a2), a, b, c).dot(q.clone()).abs() > 0.99999_f32);
+    /// }
+    /// for (a1, a2) in seqs().into_iter().zip(seqs()) {
+    ///     let (a, b, c) = q.to_euler(EulerSeq::Extrinsic(a1));
+    ///     assert!(QNum::from_euler(EulerSeq::Extrinsic(a2), a, b, c).dot(q.clone()).abs() > 0.99999_f32);
+    /// }
+    /// ```
+    /// ```
+    /// use tmn::quaternion::{QNum, EulerSeq, EulerAxes};
+    /// //Складывание рамок: тангаж 90 градусов
+    /// let q = QNum::from_euler(EulerSeq::Intrinsic(EulerAxes::Zyx), 0.3_f32, std::f32::consts::PI/2_f32, 0.2_f32);
+    /// let (a, b, c) = q.to_euler(EulerSeq::Intrinsic(EulerAxes::Zyx));
+    /// assert_eq!(0_f32, c);
+    /// assert!((a-0.1_f32).abs() < 0.001);
+    /// assert!((b-std::f32::consts::PI/2_f32).abs() < 0.001);
+    /// let q1 = QNum::from_euler(EulerSeq::Intrinsic(EulerAxes::Zyx), a, b, c);
+    /// assert!(q1.dot(q).abs() > 0.99999_f32);
+    /// ```
+    pub fn to_euler(&self, seq:EulerSeq) -> (f32, f32, f32){
+        //Общий метод Бернардеса-Виоле для внешних поворотов,
+        //внутренние повороты сводятся к внешним в обратном порядке
+        let (extrinsic, (i, j, k)) = match seq {
+            EulerSeq::Intrinsic(axes) => {
+                let (i, j, k) = axes.indices();
+                (false, (k, j, i))
+            },
+            EulerSeq::Extrinsic(axes) => (true, axes.indices())
+        };
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        let sign = ((i as i32 - j as i32)*(j as i32 - k as i32)*(k as i32 - i as i32)/2) as f32;
+        let q = self.normalize();
+        let (a, b, c, d) = if proper {
+            (q.r, q.vec_component(i), q.vec_component(j), q.vec_component(k)*sign)
+        } else {
+            (q.r - q.vec_component(j), q.vec_component(i) + q.vec_component(k)*sign,
+             q.vec_component(j) + q.r, q.vec_component(k)*sign - q.vec_component(i))
+        };
+        let mut ang1 = 2_f32*c.hypot(d).atan2(a.hypot(b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        let eps = 0.0005_f32;
+        let (mut ang0, mut ang2) = (half_sum - half_diff, half_sum + half_diff);
+        //При складывании рамок определена только сумма (или разность) углов,
+        //последний в исходной последовательности угол полагается равным нулю
+        if ang1.abs() <= eps {
+            (ang0, ang2) = if extrinsic { (2_f32*half_sum, 0_f32) } else { (0_f32, 2_f32*half_sum) };
+        } else if (ang1 - PI).abs() <= eps {
+            (ang0, ang2) = if extrinsic { (-2_f32*half_diff, 0_f32) } else { (0_f32, 2_f32*half_diff) };
+        }
+        if !proper {
+            ang2 *= sign;
+            ang1 -= PI/2_f32;
+        }
+        if !extrinsic {
+            std::mem::swap(&mut ang0, &mut ang2);
+        }
+        let wrap = |a:f32| if a < -PI { a + 2_f32*PI } else if a > PI { a - 2_f32*PI } else { a };
+        (wrap(ang0), ang1, wrap(ang2))
+    }
+}
@@ -0,0 +1,87 @@
+//!Integration of angular velocity
+use super::QNum;
+use crate::linalg;
+
+impl QNum {
+    //Производная ориентации 0.5·q·(0, w)
+    fn derivative(&self, w:(f32, f32, f32)) -> QNum{
+        self.mult_q(QNum::make_from_r(0_f32, w.0, w.1, w.2)).mult_r(0.5_f32)
+    }
+    ///The method that advances the orientation by the body-frame angular velocity 'w' (in radians per second), constant during the time 'dt'. The exponential map is used, which is exact for a constant angular velocity. The result is normalized
+    ///
+    ///Метод, продвигающий ориентацию на угловую скорость 'w' (в радианах в секунду) в связанной системе координат, постоянную в течение времени 'dt'. Используется экспоненциальное отображение, точное для постоянной угловой скорости. Результат нормализуется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let mut q = QNum::make_from_a(0.3_f32, (1_f32, 0_f32, 0_f32));
+    /// for _ in 0..100 {
+    ///     q = q.integrate_exp((0_f32, 0_f32, 0.5_f32), 0.01_f32);
+    /// }
+    /// let e = QNum::make_from_a(0.3_f32, (1_f32, 0_f32, 0_f32)).mult_q(QNum::make_from_a(0.5_f32, (0_f32, 0_f32, 1_f32)));
+    /// assert!(q.dot(e) > 0.99999_f32);
+    /// ```
+    pub fn integrate_exp(&self, w:(f32, f32, f32), dt:f32) -> QNum{
+        self.mult_q(QNum::from_rotation_vector(linalg::v_mult_r(w, dt))).normalize()
+    }
+    ///The method that advances the orientation by the body-frame angular velocity, which changes linearly from 'w0' to 'w1' (in radians per second) during the time 'dt'. The quaternion kinematics equation dq/dt = 0.5·q·(0, w) is integrated by the fourth-order Runge-Kutta method. The result is normalized
+    ///
+    ///Метод, продвигающий ориентацию на угловую скорость в связанной системе координат, линейно меняющуюся от 'w0' до 'w1' (в радианах в секунду) в течение времени 'dt'. Кинематическое уравнение кватерниона dq/dt = 0.5·q·(0, w) интегрируется методом Рунге-Кутты четвертого порядка. Результат нормализуется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let w = (0.3_f32, -0.4_f32, 1.2_f32);
+    /// let mut q = QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32);
+    /// for _ in 0..200 {
+    ///     q = q.integrate_rk4(w, w, 0.01_f32);
+    /// }
+    /// //Постоянная скорость: поворот на |w|·t вокруг оси w
+    /// let e = QNum::make_from_a(1.3_f32*2_f32, w);
+    /// assert!(q.dot(e) > 0.99999_f32);
+    /// ```
+    pub fn integrate_rk4(&self, w0:(f32, f32, f32), w1:(f32, f32, f32), dt:f32) -> QNum{
+        let wm = linalg::v_mult_r(linalg::v_add(w0, w1), 0.5_f32);
+        let k1 = self.derivative(w0);
+        let k2 = self.add_q(k1.mult_r(dt/2_f32)).derivative(wm);
+        let k3 = self.add_q(k2.mult_r(dt/2_f32)).derivative(wm);
+        let k4 = self.add_q(k3.mult_r(dt)).derivative(w1);
+        let d = k1.add_q(k2.mult_r(2_f32)).add_q(k3.mult_r(2_f32)).add_q(k4);
+        self.add_q(d.mult_r(dt/6_f32)).normalize()
+    }
+    ///The method that advances the orientation by the successive angular increments 'ds' (integrals of the body-frame angular velocity over equal subintervals, as given by gyroscopes) with the coning correction. One, two or three increments are supported; for two and three increments the classical two- and three-sample coning corrections are added to the rotation vector. The result is normalized
+    ///
+    ///Метод, продвигающий ориентацию на последовательные приращения угла 'ds' (интегралы угловой скорости в связанной системе координат по равным подынтервалам, как их выдают гироскопы) с конической поправкой. Поддерживаются одно, два или три приращения; для двух и трех приращений к вектору поворота добавляются классические двух- и трехотсчетные конические поправки. Результат нормализуется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let w = (0_f32, 2_f32, 0_f32);
+    /// let d = (0_f32, 0.01_f32, 0_f32);
+    /// let mut q2 = QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32);
+    /// let mut q3 = QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32);
+    /// for _ in 0..50 {
+    ///     q2 = q2.integrate_coning(&[d, d]);
+    ///     q3 = q3.integrate_coning(&[d, d, d]);
+    /// }
+    /// assert!(q2.dot(QNum::make_from_a(1_f32, w)) > 0.99999_f32);
+    /// assert!(q3.dot(QNum::make_from_a(1.5_f32, w)) > 0.99999_f32);
+    /// ```
+    pub fn integrate_coning(&self, ds:&[(f32, f32, f32)]) -> QNum{
+        let phi = match ds {
+            [d] => *d,
+            [d1, d2] => {
+                let c = linalg::v_mult_r(linalg::v_cross(*d1, *d2), 2_f32/3_f32);
+                linalg::v_add(linalg::v_add(*d1, *d2), c)
+            },
+            [d1, d2, d3] => {
+                let c1 = linalg::v_mult_r(linalg::v_cross(*d1, *d3), 33_f32/80_f32);
+                let d31 = linalg::v_add(*d3, linalg::v_mult_r(*d1, -1_f32));
+                let c2 = linalg::v_mult_r(linalg::v_cross(*d2, d31), 57_f32/80_f32);
+                linalg::v_add(linalg::v_add(linalg::v_add(*d1, *d2), linalg::v_add(*d3, c1)), c2)
+            },
+            _ => panic!("One, two or three angular increments are expected")
+        };
+        self.mult_q(QNum::from_rotation_vector(phi)).normalize()
+    }
+}
@@ -0,0 +1,117 @@
+//!Interpolation of rotation quaternions
+use super::QNum;
+
+impl QNum {
+    ///The function for spherical linear interpolation (SLERP) between unit quaternions 'a' and 'b'. The shortest path is taken: if the quaternions lie in different hemispheres, 'b' is replaced by '-b'. For very close quaternions NLERP is used
+    ///
+    ///Функция сферической линейной интерполяции (SLERP) между единичными кватернионами 'a' и 'b'. Выбирается кратчайший путь: если кватернионы лежат в разных полусферах, 'b' заменяется на '-b'. Для очень близких кватернионов используется NLERP
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_a(0_f32, (0_f32, 0_f32, 1_f32));
+    /// let b = QNum::make_from_a(std::f32::consts::PI/2_f32, (0_f32, 0_f32, 1_f32));
+    /// //Концы интервала воспроизводятся точно
+    /// assert!(QNum::slerp(a.clone(), b.clone(), 0_f32) == a);
+    /// assert!(QNum::slerp(a.clone(), b.clone(), 1_f32) == b);
+    /// //Постоянная угловая скорость: угол поворота пропорционален t
+    /// for n in 1..4 {
+    ///     let t = n as f32/4_f32;
+    ///     let q = QNum::slerp(a.clone(), b.clone(), t);
+    ///     let ang = 2_f32*q.dot(a.clone()).acos();
+    ///     assert!((ang - t*std::f32::consts::PI/2_f32).abs() < 0.0001);
+    ///     assert!((q.modl() - 1_f32).abs() < 0.000001);
+    /// }
+    /// ```
+    pub fn slerp(a:QNum, b:QNum, t:f32) -> QNum{
+        let mut d = a.dot(b.clone());
+        let b = if d < 0_f32 { d = -d; -b } else { b };
+        if t == 0_f32 {
+            return a;
+        }
+        if t == 1_f32 {
+            return b;
+        }
+        if d > 0.9995 {
+            return QNum::nlerp(a, b, t);
+        }
+        let th = d.acos();
+        let s = th.sin();
+        a.mult_r(((1_f32-t)*th).sin()/s).add_q(b.mult_r((t*th).sin()/s))
+    }
+    ///The function for normalized linear interpolation (NLERP) between unit quaternions 'a' and 'b'. It is faster than SLERP, but the angular velocity is not constant. The shortest path is taken
+    ///
+    ///Функция нормализованной линейной интерполяции (NLERP) между единичными кватернионами 'a' и 'b'. Быстрее SLERP, но угловая скорость не постоянна. Выбирается кратчайший путь
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_a(0_f32, (1_f32, 0_f32, 0_f32));
+    /// let b = QNum::make_from_a(std::f32::consts::PI/2_f32, (1_f32, 0_f32, 0_f32));
+    /// assert!(QNum::nlerp(a.clone(), b.clone(), 0_f32) == a);
+    /// assert!(QNum::nlerp(a.clone(), b.clone(), 1_f32) == b);
+    /// //Середина совпадает с SLERP
+    /// let q = QNum::nlerp(a.clone(), b.clone(), 0.5_f32);
+    /// let ang = 2_f32*q.dot(a).acos();
+    /// assert!((ang - std::f32::consts::PI/4_f32).abs() < 0.0001);
+    /// ```
+    pub fn nlerp(a:QNum, b:QNum, t:f32) -> QNum{
+        let b = if a.dot(b.clone()) < 0_f32 { -b } else { b };
+        if t == 0_f32 {
+            return a;
+        }
+        if t == 1_f32 {
+            return b;
+        }
+        a.mult_r(1_f32-t).add_q(b.mult_r(t)).normalize()
+    }
+    ///The function that returns the SQUAD control point for the key 'cur' with the neighbouring keys 'prev' and 'next'
+    ///
+    ///Функция, возвращающая контрольную точку SQUAD для ключа 'cur' с соседними ключами 'prev' и 'next'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// //Для равномерного вращения вокруг одной оси контрольная точка совпадает с ключом
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// let s = QNum::squad_control(QNum::make_from_a(0.1_f32, z), QNum::make_from_a(0.3_f32, z), QNum::make_from_a(0.5_f32, z));
+    /// let (r, i, j, k) = s.get();
+    /// let (r1, i1, j1, k1) = QNum::make_from_a(0.3_f32, z).get();
+    /// assert!((r-r1).abs() < 0.00001 && (i-i1).abs() < 0.00001 && (j-j1).abs() < 0.00001 && (k-k1).abs() < 0.00001);
+    /// ```
+    pub fn squad_control(prev:QNum, cur:QNum, next:QNum) -> QNum{
+        let prev = if cur.dot(prev.clone()) < 0_f32 { -prev } else { prev };
+        let next = if cur.dot(next.clone()) < 0_f32 { -next } else { next };
+        let inv = cur.conj();
+        let l = inv.mult_q(prev).ln().add_q(inv.mult_q(next).ln());
+        cur.mult_q(l.mult_r(-0.25_f32).exp()).normalize()
+    }
+    ///The function for spherical cubic interpolation (SQUAD) between the keys 'q1' and 'q2'. The control points are calculated automatically from the neighbouring keys 'q0' and 'q3'
+    ///
+    ///Функция сферической кубической интерполяции (SQUAD) между ключами 'q1' и 'q2'. Контрольные точки вычисляются автоматически по соседним ключам 'q0' и 'q3'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// let q: Vec<QNum> = (0..4).map(|n| QNum::make_from_a(0.4_f32*n as f32, z)).collect();
+    /// let sq = |t:f32| QNum::squad(q[0].clone(), q[1].clone(), q[2].clone(), q[3].clone(), t);
+    /// assert!(sq(0_f32) == q[1]);
+    /// let (r, i, j, k) = sq(1_f32).get();
+    /// let (r1, i1, j1, k1) = q[2].get();
+    /// assert!((r-r1).abs() < 0.00001 && (i-i1).abs() < 0.00001 && (j-j1).abs() < 0.00001 && (k-k1).abs() < 0.00001);
+    /// //Равномерно расположенные ключи дают постоянную угловую скорость
+    /// for n in 1..4 {
+    ///     let t = n as f32/4_f32;
+    ///     let ang = 2_f32*sq(t).dot(q[1].clone()).min(1_f32).acos();
+    ///     assert!((ang - 0.4_f32*t).abs() < 0.0001);
+    /// }
+    /// ```
+    pub fn squad(q0:QNum, q1:QNum, q2:QNum, q3:QNum, t:f32) -> QNum{
+        let q2 = if q1.dot(q2.clone()) < 0_f32 { -q2 } else { q2 };
+        let s1 = QNum::squad_control(q0, q1.clone(), q2.clone());
+        let s2 = QNum::squad_control(q1.clone(), q2.clone(), q3);
+        let h = 2_f32*t*(1_f32-t);
+        QNum::slerp(QNum::slerp(q1, q2, t), QNum::slerp(s1, s2, t), h)
+    }
+}
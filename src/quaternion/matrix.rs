@@ -0,0 +1,115 @@
+//!Conversion between quaternions and rotation matrices
+use super::QNum;
+use crate::linalg;
+
+impl QNum {
+    ///The method that returns the 3x3 rotation matrix (row by row) of the rotation given by the quaternion. A quaternion of non-unit length is treated as its normalized version
+    ///
+    ///Метод, возвращающий матрицу поворота 3x3 (по строкам) для поворота, заданного кватернионом. Кватернион неединичной длины рассматривается как его нормализованная версия
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(std::f32::consts::PI/2_f32, (0_f32, 0_f32, 1_f32));
+    /// let m = q.to_rotation_matrix();
+    /// //Поворот на 90 градусов вокруг оси z переводит ось x в ось y
+    /// let e = [[0_f32, -1_f32, 0_f32], [1_f32, 0_f32, 0_f32], [0_f32, 0_f32, 1_f32]];
+    /// for r in 0..3 {
+    ///     for c in 0..3 {
+    ///         assert!((m[r][c]-e[r][c]).abs() < 0.000001);
+    ///     }
+    /// }
+    /// ```
+    pub fn to_rotation_matrix(&self) -> [[f32; 3]; 3]{
+        let n = self.norm();
+        let s = if n == 0_f32 { 0_f32 } else { 2_f32/n };
+        let (r, i, j, k) = self.get();
+        [
+            [1_f32 - s*(j*j + k*k), s*(i*j - k*r), s*(i*k + j*r)],
+            [s*(i*j + k*r), 1_f32 - s*(i*i + k*k), s*(j*k - i*r)],
+            [s*(i*k - j*r), s*(j*k + i*r), 1_f32 - s*(i*i + j*j)]
+        ]
+    }
+    ///The method that returns the 4x4 homogeneous transformation matrix (row by row) of the rotation given by the quaternion
+    ///
+    ///Метод, возвращающий однородную матрицу преобразования 4x4 (по строкам) для поворота, заданного кватернионом
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(std::f32::consts::PI/3_f32, (1_f32, 0_f32, 0_f32));
+    /// let m = q.to_rotation_matrix();
+    /// let h = q.to_homogeneous();
+    /// for r in 0..3 {
+    ///     assert_eq!(m[r][0..3], h[r][0..3]);
+    ///     assert_eq!(0_f32, h[r][3]);
+    /// }
+    /// assert_eq!([0_f32, 0_f32, 0_f32, 1_f32], h[3]);
+    /// ```
+    pub fn to_homogeneous(&self) -> [[f32; 4]; 4]{
+        let m = self.to_rotation_matrix();
+        let mut ret = [[0_f32; 4]; 4];
+        for r in 0..3 {
+            ret[r][0..3].copy_from_slice(&m[r]);
+        }
+        ret[3][3] = 1_f32;
+        ret
+    }
+    ///The function that creates a unit quaternion from a 3x3 rotation matrix (row by row) with a non-negative real part. A slightly non-orthogonal matrix is first replaced by the nearest orthogonal one. Shepperd's method is used: the quaternion is restored from the largest of the diagonal combinations, which keeps the calculation stable for any angle
+    ///
+    ///Функция, создающая единичный кватернион с неотрицательной действительной частью из матрицы поворота 3x3 (по строкам). Слегка неортогональная матрица сначала заменяется ближайшей ортогональной. Используется метод Шеппарда: кватернион восстанавливается по наибольшей из диагональных комбинаций, что сохраняет устойчивость вычислений для любого угла
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// //Поворот на 180 градусов вокруг оси y
+    /// let m = [[-1_f32, 0_f32, 0_f32], [0_f32, 1_f32, 0_f32], [0_f32, 0_f32, -1_f32]];
+    /// assert_eq!((0_f32, 0_f32, 1_f32, 0_f32), QNum::from_rotation_matrix(m).get());
+    /// ```
+    /// ```
+    /// use tmn::quaternion::QNum;
+    /// //Прямое и обратное преобразование для углов во всех четвертях
+    /// let axes = [(1_f32, 0_f32, 0_f32), (0_f32, 1_f32, 0_f32), (0_f32, 0_f32, 1_f32), (0.48_f32, -0.6_f32, 0.64_f32)];
+    /// for axis in axes {
+    ///     for n in -8..=8 {
+    ///         let q = QNum::make_from_a(n as f32*std::f32::consts::PI/4_f32 + 0.1_f32, axis);
+    ///         let q1 = QNum::from_rotation_matrix(q.to_rotation_matrix());
+    ///         assert!((q.dot(q1).abs() - 1_f32).abs() < 0.00001);
+    ///     }
+    /// }
+    /// ```
+    /// ```
+    /// use tmn::quaternion::QNum;
+    /// //Слегка искаженная матрица поворота
+    /// let q = QNum::make_from_a(1_f32, (0_f32, 0.6_f32, 0.8_f32));
+    /// let mut m = q.to_rotation_matrix();
+    /// m[0][1] += 0.01_f32;
+    /// m[2][0] -= 0.01_f32;
+    /// let q1 = QNum::from_rotation_matrix(m);
+    /// assert!((q1.modl() - 1_f32).abs() < 0.000001);
+    /// assert!(q.dot(q1).abs() > 0.9999_f32);
+    /// ```
+    pub fn from_rotation_matrix(m:[[f32; 3]; 3]) -> QNum{
+        let m = linalg::m3_orthonormalize(&m);
+        let t = m[0][0] + m[1][1] + m[2][2];
+        let q = if t >= m[0][0] && t >= m[1][1] && t >= m[2][2] {
+            let r = 0.5_f32*(1_f32 + t).max(0_f32).powf(0.5);
+            let d = 0.25_f32/r;
+            QNum{r, i:(m[2][1] - m[1][2])*d, j:(m[0][2] - m[2][0])*d, k:(m[1][0] - m[0][1])*d}
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let i = 0.5_f32*(1_f32 + m[0][0] - m[1][1] - m[2][2]).max(0_f32).powf(0.5);
+            let d = 0.25_f32/i;
+            QNum{r:(m[2][1] - m[1][2])*d, i, j:(m[0][1] + m[1][0])*d, k:(m[0][2] + m[2][0])*d}
+        } else if m[1][1] >= m[2][2] {
+            let j = 0.5_f32*(1_f32 - m[0][0] + m[1][1] - m[2][2]).max(0_f32).powf(0.5);
+            let d = 0.25_f32/j;
+            QNum{r:(m[0][2] - m[2][0])*d, i:(m[0][1] + m[1][0])*d, j, k:(m[1][2] + m[2][1])*d}
+        } else {
+            let k = 0.5_f32*(1_f32 - m[0][0] - m[1][1] + m[2][2]).max(0_f32).powf(0.5);
+            let d = 0.25_f32/k;
+            QNum{r:(m[1][0] - m[0][1])*d, i:(m[0][2] + m[2][0])*d, j:(m[1][2] + m[2][1])*d, k}
+        };
+        let q = q.normalize();
+        if q.r < 0_f32 { -q } else { q }
+    }
+}
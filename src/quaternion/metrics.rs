@@ -0,0 +1,88 @@
+//!Distances and errors between rotation quaternions
+use super::QNum;
+
+impl QNum {
+    ///The function that returns the geodesic (angular) distance between the rotations 'a' and 'b', that is the angle in radians (from 0 to PI) of the relative rotation. 'q' and '-q' are treated as the same rotation
+    ///
+    ///Функция, возвращающая геодезическое (угловое) расстояние между поворотами 'a' и 'b', то есть угол в радианах (от 0 до PI) относительного поворота. 'q' и '-q' считаются одним поворотом
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_a(0.3_f32, (0_f32, 1_f32, 0_f32));
+    /// let b = -QNum::make_from_a(1_f32, (0_f32, 1_f32, 0_f32));
+    /// assert!((QNum::angular_distance(a, b)-0.7_f32).abs() < 0.00001);
+    /// ```
+    pub fn angular_distance(a:QNum, b:QNum) -> f32{
+        let a = a.normalize();
+        let b = b.normalize();
+        //Через модуль разности устойчивее, чем через арккосинус скалярного произведения
+        let d = a.add_q(-b.clone()).modl().min(a.add_q(b).modl());
+        4_f32*(d/2_f32).min(1_f32).asin()
+    }
+    ///The function that returns the chordal distance between the rotations 'a' and 'b', min(|a - b|, |a + b|) for unit quaternions. It equals 2·sin(θ/4), where θ is the angular distance
+    ///
+    ///Функция, возвращающая хордовое расстояние между поворотами 'a' и 'b', min(|a - b|, |a + b|) для единичных кватернионов. Оно равно 2·sin(θ/4), где θ - угловое расстояние
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_a(0_f32, (0_f32, 0_f32, 1_f32));
+    /// let b = QNum::make_from_a(2_f32, (0_f32, 0_f32, 1_f32));
+    /// assert!((QNum::chordal_distance(a.clone(), b.clone())-2_f32*(0.5_f32).sin()).abs() < 0.00001);
+    /// assert!((QNum::chordal_distance(a, -b)-2_f32*(0.5_f32).sin()).abs() < 0.00001);
+    /// ```
+    pub fn chordal_distance(a:QNum, b:QNum) -> f32{
+        let a = a.normalize();
+        let b = b.normalize();
+        a.add_q(-b.clone()).modl().min(a.add_q(b).modl())
+    }
+    ///The function that returns the rotation error of the estimation 'est' relative to the true rotation 'truth': the angle of the relative rotation truth*·est (from 0 to PI) and its rotation vector in the body frame, that is the per-axis breakdown of the error
+    ///
+    ///Функция, возвращающая ошибку поворота оценки 'est' относительно истинного поворота 'truth': угол относительного поворота truth*·est (от 0 до PI) и его вектор поворота в связанной системе координат, то есть разложение ошибки по осям
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let truth = QNum::make_from_a(1_f32, (1_f32, 0_f32, 0_f32));
+    /// let est = -truth.mult_q(QNum::make_from_a(0.05_f32, (0_f32, 0_f32, 1_f32)));
+    /// let (ang, (x, y, z)) = QNum::rotation_error(est, truth);
+    /// assert!((ang-0.05_f32).abs() < 0.00001);
+    /// assert!(x.abs() < 0.00001 && y.abs() < 0.00001 && (z-0.05_f32).abs() < 0.00001);
+    /// ```
+    pub fn rotation_error(est:QNum, truth:QNum) -> (f32, (f32, f32, f32)){
+        let rel = truth.normalize().conj().mult_q(est.normalize());
+        let v = rel.to_rotation_vector();
+        ((v.0*v.0 + v.1*v.1 + v.2*v.2).powf(0.5), v)
+    }
+    ///The function that returns the geodesic loss between the rotations 'est' and 'truth', the squared angular distance. It is smooth near zero error
+    ///
+    ///Функция, возвращающая геодезическую функцию потерь между поворотами 'est' и 'truth' - квадрат углового расстояния. Она гладкая вблизи нулевой ошибки
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_a(0.2_f32, (1_f32, 1_f32, 1_f32));
+    /// assert!((QNum::geodesic_loss(a, QNum::make_from_a(0.5_f32, (1_f32, 1_f32, 1_f32)))-0.09_f32).abs() < 0.00001);
+    /// ```
+    pub fn geodesic_loss(est:QNum, truth:QNum) -> f32{
+        let d = QNum::angular_distance(est, truth);
+        d*d
+    }
+    ///The function that returns the chordal loss between the rotations 'est' and 'truth', 1 - (est·truth)² for unit quaternions. It is cheap, needs no trigonometric functions and does not depend on the signs of the quaternions
+    ///
+    ///Функция, возвращающая хордовую функцию потерь между поворотами 'est' и 'truth', 1 - (est·truth)² для единичных кватернионов. Она дешевая, не требует тригонометрических функций и не зависит от знаков кватернионов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_a(0.3_f32, (0_f32, 1_f32, 0_f32));
+    /// assert!(QNum::chordal_loss(a.clone(), -a.clone()) < 0.000001);
+    /// let b = QNum::make_from_a(std::f32::consts::PI, (1_f32, 0_f32, 0_f32));
+    /// assert!((QNum::chordal_loss(QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32), b)-1_f32).abs() < 0.00001);
+    /// ```
+    pub fn chordal_loss(est:QNum, truth:QNum) -> f32{
+        let d = est.normalize().dot(truth.normalize());
+        (1_f32 - d*d).max(0_f32)
+    }
+}
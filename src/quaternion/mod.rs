@@ -2,6 +2,23 @@
 use std::ops::Neg;
 use crate::cassette;
 use crate::complex::CNum;
+use crate::linalg;
+
+mod interpolation;
+mod matrix;
+mod euler;
+mod axis_angle;
+mod rotation;
+mod average;
+mod integration;
+mod unit;
+mod metrics;
+mod canonical;
+mod swing_twist;
+mod representation;
+
+pub use euler::{EulerAxes, EulerSeq};
+pub use unit::UnitQNum;
 ///The structure storing the quaternion
 ///
 /// Структура хранящая кватернион
@@ -23,7 +40,6 @@ impl QNum {
     /// let a = QNum::make_zero();
     /// assert!(QNum::make_from_r(0_f32, 0_f32, 0_f32, 0_f32)==a);
     /// ```
-
     pub fn make_zero()->Self{
         QNum{
             r:0_f32,
@@ -57,16 +73,24 @@ impl QNum {
         let (j, k) = w2.get();
         Self{ r, i, j, k }
     }
-    ///The function that creates a rotation quaternion from the angle 'ang' and the axis of rotation given by a vector in the form of a tuple
+    ///The function that creates a rotation quaternion from the angle 'ang' and the axis of rotation given by a vector in the form of a tuple. The axis is normalized, so the result is always a unit quaternion. A zero axis gives the identity rotation
     ///
-    ///Функция, создающая кватернион поворота из угла 'ang' и оси вращения, заданной вектором в виде кортежа
+    ///Функция, создающая кватернион поворота из угла 'ang' и оси вращения, заданной вектором в виде кортежа. Ось нормализуется, поэтому результат всегда является единичным кватернионом. Нулевая ось дает тождественный поворот
     /// # Example
     ///```
     /// use tmn::quaternion::QNum;
     /// let c = QNum::make_from_a(90_f32*std::f32::consts::PI/180_f32, (0_f32, 0_f32, 1_f32));
     /// assert_eq!(((2_f32).powf(0.5)/2_f32, 0_f32, 0_f32, (2_f32).powf(0.5)/2_f32), c.get());
+    /// let c = QNum::make_from_a(90_f32*std::f32::consts::PI/180_f32, (0_f32, 0_f32, 5_f32));
+    /// assert_eq!(((2_f32).powf(0.5)/2_f32, 0_f32, 0_f32, (2_f32).powf(0.5)/2_f32), c.get());
+    /// let c = QNum::make_from_a(1_f32, (0_f32, 0_f32, 0_f32));
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), c.get());
     /// ```
     pub fn make_from_a(ang:f32, vec:(f32, f32, f32)) ->Self{
+        let vec = match linalg::v_normalize(vec) {
+            Some(v) => v,
+            None => return QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32)
+        };
         Self{
             r:(ang/2.0).cos(),
             i:(ang/2.0).sin()*vec.0,
@@ -84,6 +108,7 @@ impl QNum {
     /// let c = a.clone();
     /// assert_eq!((1_f32, 1_f32, 1_f32, 1_f32), c.get());
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn clone(&self) -> QNum{QNum{r:self.r,i:self.i,j:self.j,k:self.k } }
     ///The method for obtaining quaternion coefficients in the form of a tuple
     ///
@@ -232,6 +257,81 @@ impl QNum {
     /// a = a.inv();
     /// assert_eq!((0.25_f32, -0.25_f32, -0.25_f32, -0.25_f32), a.get());
     pub fn inv(&self) -> QNum{ self.conj().mult_r(1_f32/self.norm()) }
+    ///The method that returns the dot product of quaternions as 4-dimensional vectors
+    ///
+    /// Метод, возвращающий скалярное произведение кватернионов как 4-мерных векторов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32);
+    /// assert_eq!(30_f32, a.dot(QNum::make_from_r(1_f32, 2_f32, 3_f32, 4_f32)));
+    /// ```
+    pub fn dot(&self, v:QNum) -> f32{ self.r*v.r + self.i*v.i + self.j*v.j + self.k*v.k }
+    ///The method that returns the quaternion of unit length. The zero quaternion is returned unchanged
+    ///
+    /// Метод, возвращающий кватернион единичной длины. Нулевой кватернион возвращается без изменений
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_r(1_f32, 1_f32, 1_f32, 1_f32).normalize();
+    /// assert_eq!((0.5_f32, 0.5_f32, 0.5_f32, 0.5_f32), a.get());
+    /// ```
+    pub fn normalize(&self) -> QNum{
+        let m = self.modl();
+        if m == 0_f32 {
+            return self.clone();
+        }
+        self.mult_r(1_f32/m)
+    }
+    ///The method that returns the exponent of a quaternion
+    ///
+    /// Метод, возвращающий экспоненту кватерниона
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_r(0_f32, 0_f32, 0_f32, std::f32::consts::PI/4_f32).exp();
+    /// let (r, i, j, k) = a.get();
+    /// assert!((r-(2_f32).powf(0.5)/2_f32).abs() < 0.000001);
+    /// assert!((k-(2_f32).powf(0.5)/2_f32).abs() < 0.000001);
+    /// assert_eq!((0_f32, 0_f32), (i, j));
+    /// ```
+    pub fn exp(&self) -> QNum{
+        let v = (self.i*self.i + self.j*self.j + self.k*self.k).powf(0.5);
+        let e = self.r.exp();
+        //sin(v)/v, при малых v - ряд Тейлора
+        let s = if v < 0.0001 { 1_f32 - v*v/6_f32 } else { v.sin()/v };
+        QNum{ r:e*v.cos(), i:e*s*self.i, j:e*s*self.j, k:e*s*self.k }
+    }
+    ///The method that returns the natural logarithm of a quaternion (the principal value). For a negative real quaternion the axis is not defined, and the rotation by π about the x-axis is chosen: ln(-1) = π·i
+    ///
+    /// Метод, возвращающий натуральный логарифм кватерниона (главное значение). Для отрицательного действительного кватерниона ось не определена, и выбирается поворот на π вокруг оси x: ln(-1) = π·i
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let a = QNum::make_from_r(0_f32, 0.3_f32, -0.2_f32, 0.5_f32);
+    /// let (r, i, j, k) = a.exp().ln().get();
+    /// assert!(r.abs() < 0.000001);
+    /// assert!((i-0.3_f32).abs() < 0.000001);
+    /// assert!((j+0.2_f32).abs() < 0.000001);
+    /// assert!((k-0.5_f32).abs() < 0.000001);
+    /// let (r, i, j, k) = QNum::make_from_r(-2_f32, 0_f32, 0_f32, 0_f32).ln().get();
+    /// assert_eq!((2_f32.ln(), std::f32::consts::PI, 0_f32, 0_f32), (r, i, j, k));
+    /// ```
+    pub fn ln(&self) -> QNum{
+        let v = (self.i*self.i + self.j*self.j + self.k*self.k).powf(0.5);
+        let m = self.modl();
+        if v == 0_f32 && self.r < 0_f32 {
+            return QNum{ r:m.ln(), i:std::f32::consts::PI, j:0_f32, k:0_f32 };
+        }
+        let a = v.atan2(self.r);
+        //a/v, при малых v стремится к 1/r
+        let s = if v < 0.0001 * m && self.r > 0_f32 { 1_f32/self.r } else { a/v };
+        QNum{ r:m.ln(), i:s*self.i, j:s*self.j, k:s*self.k }
+    }
     ///The method for setting values to specific coefficients
     ///
     /// Метод для установки значений в конкретные коэффициенты
@@ -244,7 +344,6 @@ impl QNum {
     /// a = a.set(quaternion::R|quaternion::J, 3_f32);
     /// assert_eq!((3_f32, 0_f32, 3_f32, 0_f32), a.get());
     /// ```
-
     pub fn set(&self, c:u8, v:f32) -> Self{
         let mut ret = self.clone();
         if cassette::cassette::eq(c, 0){
@@ -0,0 +1,124 @@
+//!Matrix representations of quaternions
+use super::QNum;
+use crate::complex::CNum;
+
+impl QNum {
+    ///The method that returns the quaternion q = z1 + z2·j (z1 = r + i·i, z2 = j + k·i, see 'make_from_c') as the 2x2 complex matrix [[z1, z2], [-z2*, z1*]]. The product of quaternions corresponds to the product of matrices, and unit quaternions are mapped to SU(2)
+    ///
+    ///Метод, возвращающий кватернион q = z1 + z2·j (z1 = r + i·i, z2 = j + k·i, см. 'make_from_c') в виде комплексной матрицы 2x2 [[z1, z2], [-z2*, z1*]]. Произведению кватернионов соответствует произведение матриц, а единичные кватернионы отображаются в SU(2)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// use tmn::complex::CNum;
+    /// let p = QNum::make_from_r(1_f32, 2_f32, -1_f32, 3_f32);
+    /// let q = QNum::make_from_r(-2_f32, 1_f32, 4_f32, 1_f32);
+    /// let (a, b) = (p.to_complex_matrix(), q.to_complex_matrix());
+    /// let ab = p.mult_q(q).to_complex_matrix();
+    /// for r in 0..2 {
+    ///     for c in 0..2 {
+    ///         let e = a[r][0].mult_c(b[0][c].clone()).add_c(a[r][1].mult_c(b[1][c].clone()));
+    ///         assert!(e == ab[r][c]);
+    ///     }
+    /// }
+    /// //Определитель равен норме
+    /// let det = a[0][0].mult_c(a[1][1].clone()).add_c(-a[0][1].mult_c(a[1][0].clone()));
+    /// assert!(det == CNum::make(15_f32, 0_f32));
+    /// ```
+    pub fn to_complex_matrix(&self) -> [[CNum; 2]; 2]{
+        [
+            [CNum::make(self.r, self.i), CNum::make(self.j, self.k)],
+            [CNum::make(-self.j, self.k), CNum::make(self.r, -self.i)]
+        ]
+    }
+    ///The function that creates a quaternion from the 2x2 complex matrix 'm'. It is the inverse of 'to_complex_matrix'. If the matrix does not have the form [[z1, z2], [-z2*, z1*]], the nearest such matrix is used
+    ///
+    ///Функция, создающая кватернион из комплексной матрицы 2x2 'm'. Является обратной к 'to_complex_matrix'. Если матрица не имеет вида [[z1, z2], [-z2*, z1*]], используется ближайшая матрица такого вида
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_r(0.5_f32, -1_f32, 2_f32, 0.25_f32);
+    /// assert!(QNum::from_complex_matrix(q.to_complex_matrix()) == q);
+    /// ```
+    pub fn from_complex_matrix(m:[[CNum; 2]; 2]) -> QNum{
+        let z1 = m[0][0].add_c(m[1][1].conj()).mult_r(0.5_f32);
+        let z2 = m[0][1].add_c(-m[1][0].conj()).mult_r(0.5_f32);
+        QNum::make_from_c(z1, z2)
+    }
+    ///The method that returns the 4x4 real matrix L(p) of the left multiplication by the quaternion: L(p)·q = p·q, where quaternions are columns (r, i, j, k). L(p)·L(q) = L(p·q)
+    ///
+    ///Метод, возвращающий действительную матрицу 4x4 L(p) левого умножения на кватернион: L(p)·q = p·q, где кватернионы - столбцы (r, i, j, k). L(p)·L(q) = L(p·q)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let p = QNum::make_from_r(1_f32, 2_f32, -1_f32, 3_f32);
+    /// let q = QNum::make_from_r(-2_f32, 1_f32, 4_f32, 1_f32);
+    /// let l = p.left_matrix();
+    /// let v = [-2_f32, 1_f32, 4_f32, 1_f32];
+    /// let (r, i, j, k) = p.mult_q(q.clone()).get();
+    /// for (n, e) in [r, i, j, k].into_iter().enumerate() {
+    ///     assert_eq!(e, (0..4).map(|c| l[n][c]*v[c]).sum::<f32>());
+    /// }
+    /// //Гомоморфизм
+    /// let (a, b) = (p.left_matrix(), q.left_matrix());
+    /// let ab = p.mult_q(q).left_matrix();
+    /// for r in 0..4 {
+    ///     for c in 0..4 {
+    ///         assert_eq!(ab[r][c], (0..4).map(|n| a[r][n]*b[n][c]).sum::<f32>());
+    ///     }
+    /// }
+    /// ```
+    pub fn left_matrix(&self) -> [[f32; 4]; 4]{
+        let (a, b, c, d) = self.get();
+        [
+            [a, -b, -c, -d],
+            [b, a, -d, c],
+            [c, d, a, -b],
+            [d, -c, b, a]
+        ]
+    }
+    ///The method that returns the 4x4 real matrix R(q) of the right multiplication by the quaternion: R(q)·p = p·q, where quaternions are columns (r, i, j, k). R(p)·R(q) = R(q·p), and left and right multiplication matrices commute
+    ///
+    ///Метод, возвращающий действительную матрицу 4x4 R(q) правого умножения на кватернион: R(q)·p = p·q, где кватернионы - столбцы (r, i, j, k). R(p)·R(q) = R(q·p), а матрицы левого и правого умножения коммутируют
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let p = QNum::make_from_r(1_f32, 2_f32, -1_f32, 3_f32);
+    /// let q = QNum::make_from_r(-2_f32, 1_f32, 4_f32, 1_f32);
+    /// let m = q.right_matrix();
+    /// let v = [1_f32, 2_f32, -1_f32, 3_f32];
+    /// let (r, i, j, k) = p.mult_q(q.clone()).get();
+    /// for (n, e) in [r, i, j, k].into_iter().enumerate() {
+    ///     assert_eq!(e, (0..4).map(|c| m[n][c]*v[c]).sum::<f32>());
+    /// }
+    /// //Антигомоморфизм
+    /// let (a, b) = (p.right_matrix(), q.right_matrix());
+    /// let ba = q.mult_q(p.clone()).right_matrix();
+    /// for r in 0..4 {
+    ///     for c in 0..4 {
+    ///         assert_eq!(ba[r][c], (0..4).map(|n| a[r][n]*b[n][c]).sum::<f32>());
+    ///     }
+    /// }
+    /// //Коммутирование L(p) и R(q)
+    /// let l = p.left_matrix();
+    /// for r in 0..4 {
+    ///     for c in 0..4 {
+    ///         let lr: f32 = (0..4).map(|n| l[r][n]*b[n][c]).sum();
+    ///         let rl: f32 = (0..4).map(|n| b[r][n]*l[n][c]).sum();
+    ///         assert_eq!(lr, rl);
+    ///     }
+    /// }
+    /// ```
+    pub fn right_matrix(&self) -> [[f32; 4]; 4]{
+        let (a, b, c, d) = self.get();
+        [
+            [a, -b, -c, -d],
+            [b, a, d, -c],
+            [c, -d, a, b],
+            [d, c, -b, a]
+        ]
+    }
+}
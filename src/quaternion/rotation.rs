@@ -0,0 +1,149 @@
+//!Rotation of 3D vectors and rotations between vectors
+use super::QNum;
+use crate::linalg;
+
+impl QNum {
+    ///The method that rotates the vector 'v' by the unit quaternion. The result is the same as q·v·q*, but it is calculated with two cross products instead of two quaternion products
+    ///
+    ///Метод, поворачивающий вектор 'v' единичным кватернионом. Результат совпадает с q·v·q*, но вычисляется с помощью двух векторных произведений вместо двух произведений кватернионов
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(std::f32::consts::PI/2_f32, (0_f32, 0_f32, 1_f32));
+    /// let (x, y, z) = q.rotate_vector((1_f32, 0_f32, 0_f32));
+    /// assert!(x.abs() < 0.0000001 && (y-1_f32).abs() < 0.0000001 && z.abs() < 0.0000001);
+    /// //Сравнение с q·v·q*
+    /// let q = QNum::make_from_a(0.7_f32, (1_f32, -2_f32, 3_f32));
+    /// let (_, i, j, k) = q.mult_q(QNum::make_from_r(0_f32, 4_f32, 5_f32, 6_f32)).mult_q(q.conj()).get();
+    /// let (x, y, z) = q.rotate_vector((4_f32, 5_f32, 6_f32));
+    /// assert!((x-i).abs() < 0.00001 && (y-j).abs() < 0.00001 && (z-k).abs() < 0.00001);
+    /// ```
+    pub fn rotate_vector(&self, v:(f32, f32, f32)) -> (f32, f32, f32){
+        let u = (self.i, self.j, self.k);
+        let t = linalg::v_mult_r(linalg::v_cross(u, v), 2_f32);
+        linalg::v_add(linalg::v_add(v, linalg::v_mult_r(t, self.r)), linalg::v_cross(u, t))
+    }
+    ///The method that rotates the vector 'v' by the rotation inverse to the unit quaternion, that is calculates q*·v·q
+    ///
+    ///Метод, поворачивающий вектор 'v' поворотом, обратным единичному кватерниону, то есть вычисляющий q*·v·q
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(1.3_f32, (2_f32, 1_f32, -1_f32));
+    /// let (x, y, z) = q.inverse_rotate_vector(q.rotate_vector((1_f32, 2_f32, 3_f32)));
+    /// assert!((x-1_f32).abs() < 0.00001 && (y-2_f32).abs() < 0.00001 && (z-3_f32).abs() < 0.00001);
+    /// ```
+    pub fn inverse_rotate_vector(&self, v:(f32, f32, f32)) -> (f32, f32, f32){
+        self.conj().rotate_vector(v)
+    }
+    ///The method that rotates all vectors of the slice 'vs' by the unit quaternion in place
+    ///
+    ///Метод, поворачивающий на месте все векторы среза 'vs' единичным кватернионом
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(std::f32::consts::PI, (0_f32, 1_f32, 0_f32));
+    /// let mut vs: Vec<(f32, f32, f32)> = (0..1000).map(|n| (n as f32, 1_f32, 2_f32)).collect();
+    /// q.rotate_vectors(&mut vs);
+    /// for (n, (x, y, z)) in vs.into_iter().enumerate() {
+    ///     assert!((x+n as f32).abs() < 0.001 && (y-1_f32).abs() < 0.00001 && (z+2_f32).abs() < 0.001);
+    /// }
+    /// ```
+    pub fn rotate_vectors(&self, vs:&mut [(f32, f32, f32)]){
+        for v in vs.iter_mut() {
+            *v = self.rotate_vector(*v);
+        }
+    }
+    ///The method that rotates all vectors of the slice 'vs' in place by the rotation inverse to the unit quaternion
+    ///
+    ///Метод, поворачивающий на месте все векторы среза 'vs' поворотом, обратным единичному кватерниону
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(0.4_f32, (1_f32, 1_f32, 0_f32));
+    /// let mut vs = vec![(1_f32, 0_f32, 0_f32), (0_f32, 1_f32, 0_f32), (0_f32, 0_f32, 1_f32)];
+    /// q.rotate_vectors(&mut vs);
+    /// q.inverse_rotate_vectors(&mut vs);
+    /// assert!((vs[2].2-1_f32).abs() < 0.000001 && vs[2].0.abs() < 0.000001);
+    /// ```
+    pub fn inverse_rotate_vectors(&self, vs:&mut [(f32, f32, f32)]){
+        let q = self.conj();
+        q.rotate_vectors(vs);
+    }
+    ///The function that creates the shortest-arc rotation quaternion which turns the direction of the vector 'a' into the direction of the vector 'b'. For antiparallel vectors a rotation by PI around an axis perpendicular to 'a' is returned. If one of the vectors is zero, the identity rotation is returned
+    ///
+    ///Функция, создающая кватернион поворота по кратчайшей дуге, переводящего направление вектора 'a' в направление вектора 'b'. Для противоположно направленных векторов возвращается поворот на PI вокруг оси, перпендикулярной 'a'. Если один из векторов нулевой, возвращается тождественный поворот
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::from_two_vectors((2_f32, 0_f32, 0_f32), (0_f32, 3_f32, 0_f32));
+    /// let e = QNum::make_from_a(std::f32::consts::PI/2_f32, (0_f32, 0_f32, 1_f32));
+    /// assert!(q.dot(e) > 0.9999999_f32);
+    /// //Противоположно направленные векторы
+    /// let a = (1_f32, 2_f32, 3_f32);
+    /// let q = QNum::from_two_vectors(a, (-1_f32, -2_f32, -3_f32));
+    /// let (x, y, z) = q.rotate_vector(a);
+    /// assert!((x+1_f32).abs() < 0.00001 && (y+2_f32).abs() < 0.00001 && (z+3_f32).abs() < 0.00001);
+    /// assert!((q.modl()-1_f32).abs() < 0.000001);
+    /// //Совпадающие и нулевые векторы
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), QNum::from_two_vectors(a, a).get());
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), QNum::from_two_vectors(a, (0_f32, 0_f32, 0_f32)).get());
+    /// ```
+    pub fn from_two_vectors(a:(f32, f32, f32), b:(f32, f32, f32)) -> QNum{
+        let (a, b) = match (linalg::v_normalize(a), linalg::v_normalize(b)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32)
+        };
+        let d = linalg::v_dot(a, b);
+        if d < -0.999999_f32 {
+            //Ось выбирается перпендикулярно 'a' и наименее сонаправленной с ней координатной оси
+            let e = if a.0.abs() < 0.9_f32 { (1_f32, 0_f32, 0_f32) } else { (0_f32, 1_f32, 0_f32) };
+            return QNum::make_from_a(std::f32::consts::PI, linalg::v_cross(a, e));
+        }
+        let (i, j, k) = linalg::v_cross(a, b);
+        QNum::make_from_r(1_f32 + d, i, j, k).normalize()
+    }
+    ///The function that creates the orientation which turns the local axis z into the direction 'forward' and the local axis y as close as possible to the direction 'up'. If 'up' is zero or parallel to 'forward', the shortest-arc rotation of the axis z is returned. A zero 'forward' gives the identity rotation
+    ///
+    ///Функция, создающая ориентацию, которая переводит локальную ось z в направление 'forward', а локальную ось y - максимально близко к направлению 'up'. Если 'up' нулевой или параллелен 'forward', возвращается поворот оси z по кратчайшей дуге. Нулевой 'forward' дает тождественный поворот
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::look_rotation((1_f32, 0_f32, 0_f32), (0_f32, 0_f32, 1_f32));
+    /// let (x, y, z) = q.rotate_vector((0_f32, 0_f32, 1_f32));
+    /// assert!((x-1_f32).abs() < 0.000001 && y.abs() < 0.000001 && z.abs() < 0.000001);
+    /// let (x, y, z) = q.rotate_vector((0_f32, 1_f32, 0_f32));
+    /// assert!(x.abs() < 0.000001 && y.abs() < 0.000001 && (z-1_f32).abs() < 0.000001);
+    /// //Вектор 'up' не обязан быть перпендикулярным 'forward'
+    /// let q = QNum::look_rotation((0_f32, 0_f32, 2_f32), (0_f32, 1_f32, 1_f32));
+    /// assert!((q.get().0-1_f32).abs() < 0.000001);
+    /// //Вырожденные случаи
+    /// let q = QNum::look_rotation((0_f32, 3_f32, 0_f32), (0_f32, 1_f32, 0_f32));
+    /// let (x, y, z) = q.rotate_vector((0_f32, 0_f32, 1_f32));
+    /// assert!(x.abs() < 0.000001 && (y-1_f32).abs() < 0.000001 && z.abs() < 0.000001);
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), QNum::look_rotation((0_f32, 0_f32, 0_f32), (0_f32, 1_f32, 0_f32)).get());
+    /// ```
+    pub fn look_rotation(forward:(f32, f32, f32), up:(f32, f32, f32)) -> QNum{
+        let z = match linalg::v_normalize(forward) {
+            Some(z) => z,
+            None => return QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32)
+        };
+        let c = linalg::v_cross(up, z);
+        let x = match linalg::v_normalize(c) {
+            Some(x) if linalg::v_modl(c) > 0.000001_f32*linalg::v_modl(up) => x,
+            _ => return QNum::from_two_vectors((0_f32, 0_f32, 1_f32), z)
+        };
+        let y = linalg::v_cross(z, x);
+        QNum::from_rotation_matrix([
+            [x.0, y.0, z.0],
+            [x.1, y.1, z.1],
+            [x.2, y.2, z.2]
+        ])
+    }
+}
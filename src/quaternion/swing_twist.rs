@@ -0,0 +1,72 @@
+//!Swing-twist decomposition of rotations
+use super::QNum;
+use crate::linalg;
+
+impl QNum {
+    ///The method that splits the rotation into the twist about the axis 'axis' and the remaining swing (about an axis perpendicular to 'axis'), so that q = swing·twist. The twist is applied first. If the twist is undefined (the swing is a half turn), the identity twist is returned. A zero axis gives the identity twist as well
+    ///
+    ///Метод, разделяющий поворот на закрутку вокруг оси 'axis' и оставшееся качание (вокруг оси, перпендикулярной 'axis'), так что q = swing·twist. Закрутка применяется первой. Если закрутка не определена (качание - поворот на пол-оборота), возвращается тождественная закрутка. Нулевая ось также дает тождественную закрутку
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let axis = (0_f32, 0_f32, 1_f32);
+    /// let twist = QNum::make_from_a(0.7_f32, axis);
+    /// let swing = QNum::make_from_a(0.4_f32, (1_f32, -1_f32, 0_f32));
+    /// let q = swing.mult_q(twist.clone());
+    /// let (s, t) = q.swing_twist(axis);
+    /// assert!(s.dot(swing).abs() > 0.99999_f32);
+    /// assert!(t.dot(twist).abs() > 0.99999_f32);
+    /// //Произведение воспроизводит исходный поворот
+    /// assert!(QNum::from_swing_twist(s.clone(), t.clone()).dot(q).abs() > 0.99999_f32);
+    /// //Ось качания перпендикулярна оси закрутки
+    /// let (_, i, j, k) = s.get();
+    /// assert!(k.abs() < 0.000001 && (i*i + j*j) > 0_f32);
+    /// ```
+    /// ```
+    /// use tmn::quaternion::QNum;
+    /// //Чистая закрутка и чистое качание
+    /// let q = QNum::make_from_a(1.2_f32, (0_f32, 2_f32, 0_f32));
+    /// let (s, t) = q.swing_twist((0_f32, 1_f32, 0_f32));
+    /// assert!(s.dot(QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32)).abs() > 0.99999_f32);
+    /// assert!(t.dot(q.clone()).abs() > 0.99999_f32);
+    /// let (s, t) = q.swing_twist((1_f32, 0_f32, 0_f32));
+    /// assert!(s.dot(q).abs() > 0.99999_f32);
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), t.get());
+    /// //Качание на пол-оборота
+    /// let q = QNum::make_from_a(std::f32::consts::PI, (1_f32, 0_f32, 0_f32));
+    /// let (s, t) = q.swing_twist((0_f32, 0_f32, 1_f32));
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), t.get());
+    /// assert!(s.dot(q).abs() > 0.99999_f32);
+    /// ```
+    pub fn swing_twist(&self, axis:(f32, f32, f32)) -> (QNum, QNum){
+        let identity = QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32);
+        let axis = match linalg::v_normalize(axis) {
+            Some(a) => a,
+            None => return (self.clone(), identity)
+        };
+        //Проекция векторной части на ось
+        let p = linalg::v_mult_r(axis, linalg::v_dot((self.i, self.j, self.k), axis));
+        let twist = QNum::make_from_r(self.r, p.0, p.1, p.2);
+        if twist.norm() < 0.000000000001_f32 {
+            return (self.clone(), identity);
+        }
+        let twist = twist.normalize();
+        (self.mult_q(twist.conj()), twist)
+    }
+    ///The function that composes the rotation from the swing 'swing' and the twist 'twist': q = swing·twist. It is the inverse of 'swing_twist'
+    ///
+    ///Функция, составляющая поворот из качания 'swing' и закрутки 'twist': q = swing·twist. Является обратной к 'swing_twist'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::QNum;
+    /// let q = QNum::make_from_a(2_f32, (1_f32, 2_f32, 3_f32));
+    /// let axis = (0.3_f32, -0.5_f32, 0.8_f32);
+    /// let (s, t) = q.swing_twist(axis);
+    /// let p = QNum::from_swing_twist(s, t);
+    /// let (r, i, j, k) = p.add_q(-q).get();
+    /// assert!(r.abs() < 0.00001 && i.abs() < 0.00001 && j.abs() < 0.00001 && k.abs() < 0.00001);
+    /// ```
+    pub fn from_swing_twist(swing:QNum, twist:QNum) -> QNum{ swing.mult_q(twist) }
+}
@@ -0,0 +1,363 @@
+//!Unit quaternions
+use std::ops::Neg;
+use super::{QNum, EulerSeq};
+
+///The structure storing a unit quaternion, that is a rotation. All constructors normalize or validate the length, so the inverse is the conjugate
+///
+/// Структура, хранящая единичный кватернион, то есть поворот. Все конструкторы нормализуют или проверяют длину, поэтому обратный кватернион равен сопряженному
+pub struct UnitQNum{q:QNum}
+
+impl UnitQNum {
+    ///The function for creating the identity rotation
+    ///
+    ///Функция для создания тождественного поворота
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// assert_eq!((1_f32, 0_f32, 0_f32, 0_f32), UnitQNum::make_identity().get());
+    /// ```
+    pub fn make_identity() -> Self{ UnitQNum{q:QNum::make_from_r(1_f32, 0_f32, 0_f32, 0_f32)} }
+    ///The function that creates a unit quaternion by normalizing the quaternion 'q'. The zero quaternion gives the identity rotation
+    ///
+    ///Функция, создающая единичный кватернион нормализацией кватерниона 'q'. Нулевой кватернион дает тождественный поворот
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::{QNum, UnitQNum};
+    /// let u = UnitQNum::make(QNum::make_from_r(0_f32, 3_f32, 0_f32, 4_f32));
+    /// assert_eq!((0_f32, 0.6_f32, 0_f32, 0.8_f32), u.get());
+    /// assert!(UnitQNum::make(QNum::make_zero()) == UnitQNum::make_identity());
+    /// ```
+    pub fn make(q:QNum) -> Self{
+        if q.norm() == 0_f32 {
+            return UnitQNum::make_identity();
+        }
+        UnitQNum{q:q.normalize()}
+    }
+    ///The function that creates a unit quaternion from the quaternion 'q' only if its length differs from one by no more than 'eps'. The quaternion is normalized
+    ///
+    ///Функция, создающая единичный кватернион из кватерниона 'q', только если его длина отличается от единицы не больше чем на 'eps'. Кватернион нормализуется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::{QNum, UnitQNum};
+    /// assert!(UnitQNum::make_checked(QNum::make_from_r(1.000001_f32, 0_f32, 0_f32, 0_f32), 0.00001_f32).is_some());
+    /// assert!(UnitQNum::make_checked(QNum::make_from_r(1_f32, 1_f32, 0_f32, 0_f32), 0.00001_f32).is_none());
+    /// assert!(UnitQNum::make_checked(QNum::make_from_r(f32::NAN, 0_f32, 0_f32, 0_f32), 0.001_f32).is_none());
+    /// ```
+    pub fn make_checked(q:QNum, eps:f32) -> Option<Self>{
+        //NaN не проходит сравнение, поэтому проверяется явно
+        let m = q.modl();
+        if !m.is_finite() || (m - 1_f32).abs() > eps {
+            return None;
+        }
+        Some(UnitQNum{q:q.normalize()})
+    }
+    ///The function that creates a rotation from the angle 'ang' (in radians) and the axis 'vec'. A zero axis gives the identity rotation
+    ///
+    ///Функция, создающая поворот из угла 'ang' (в радианах) и оси 'vec'. Нулевая ось дает тождественный поворот
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::{QNum, UnitQNum};
+    /// let u = UnitQNum::make_from_a(1_f32, (0_f32, 2_f32, 0_f32));
+    /// assert!(u.get_q() == QNum::make_from_a(1_f32, (0_f32, 1_f32, 0_f32)));
+    /// ```
+    pub fn make_from_a(ang:f32, vec:(f32, f32, f32)) -> Self{ UnitQNum{q:QNum::make_from_a(ang, vec)} }
+    ///The method for cloning a unit quaternion
+    ///
+    ///Метод для клонирования единичного кватерниона
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> UnitQNum{ UnitQNum{q:self.q.clone()} }
+    ///The method for obtaining quaternion coefficients in the form of a tuple
+    ///
+    ///Метод для получения коэффициентов кватерниона в виде кортежа
+    pub fn get(&self) -> (f32, f32, f32, f32){ self.q.get() }
+    ///The method that returns the rotation as a general quaternion
+    ///
+    ///Метод, возвращающий поворот в виде кватерниона общего вида
+    pub fn get_q(&self) -> QNum{ self.q.clone() }
+    ///The method that returns the inverse rotation. For a unit quaternion it is the conjugate one
+    ///
+    ///Метод, возвращающий обратный поворот. Для единичного кватерниона это сопряженный кватернион
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let u = UnitQNum::make_from_a(0.7_f32, (1_f32, 2_f32, 3_f32));
+    /// let (r, i, j, k) = u.mult_u(u.inv()).get();
+    /// assert!((r-1_f32).abs() < 0.000001 && i.abs() < 0.000001 && j.abs() < 0.000001 && k.abs() < 0.000001);
+    /// ```
+    pub fn inv(&self) -> UnitQNum{ UnitQNum{q:self.q.conj()} }
+    ///The method that returns the composition of rotations (the product of quaternions). The result is renormalized when the rounding errors move its length away from one
+    ///
+    ///Метод, возвращающий композицию поворотов (произведение кватернионов). Результат перенормируется, когда ошибки округления уводят его длину от единицы
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let step = UnitQNum::make_from_a(0.001_f32, (0.3_f32, -0.5_f32, 0.8_f32));
+    /// let mut u = UnitQNum::make_identity();
+    /// for _ in 0..100000 {
+    ///     u = u.mult_u(step.clone());
+    /// }
+    /// assert!((u.get_q().modl()-1_f32).abs() < 0.00001);
+    /// ```
+    pub fn mult_u(&self, v:UnitQNum) -> UnitQNum{
+        let q = self.q.mult_q(v.q);
+        if (q.norm() - 1_f32).abs() > 0.000002_f32 {
+            return UnitQNum{q:q.normalize()};
+        }
+        UnitQNum{q}
+    }
+    ///The method that rotates the vector 'v'
+    ///
+    ///Метод, поворачивающий вектор 'v'
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let u = UnitQNum::make_from_a(std::f32::consts::PI/2_f32, (1_f32, 0_f32, 0_f32));
+    /// let (x, y, z) = u.rotate_vector((0_f32, 1_f32, 0_f32));
+    /// assert!(x.abs() < 0.000001 && y.abs() < 0.000001 && (z-1_f32).abs() < 0.000001);
+    /// ```
+    pub fn rotate_vector(&self, v:(f32, f32, f32)) -> (f32, f32, f32){ self.q.rotate_vector(v) }
+    ///The method that rotates the vector 'v' by the inverse rotation
+    ///
+    ///Метод, поворачивающий вектор 'v' обратным поворотом
+    pub fn inverse_rotate_vector(&self, v:(f32, f32, f32)) -> (f32, f32, f32){ self.q.inverse_rotate_vector(v) }
+    ///The method that rotates all vectors of the slice 'vs' in place
+    ///
+    ///Метод, поворачивающий на месте все векторы среза 'vs'
+    pub fn rotate_vectors(&self, vs:&mut [(f32, f32, f32)]){ self.q.rotate_vectors(vs) }
+    ///The method that rotates all vectors of the slice 'vs' in place by the inverse rotation
+    ///
+    ///Метод, поворачивающий на месте все векторы среза 'vs' обратным поворотом
+    pub fn inverse_rotate_vectors(&self, vs:&mut [(f32, f32, f32)]){ self.q.inverse_rotate_vectors(vs) }
+    ///The function for spherical linear interpolation between rotations (see 'QNum::slerp')
+    ///
+    ///Функция сферической линейной интерполяции между поворотами (см. 'QNum::slerp')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = UnitQNum::make_identity();
+    /// let b = UnitQNum::make_from_a(1_f32, (0_f32, 0_f32, 1_f32));
+    /// let (ang, _) = UnitQNum::slerp(a, b, 0.25_f32).to_axis_angle();
+    /// assert!((ang-0.25_f32).abs() < 0.00001);
+    /// ```
+    pub fn slerp(a:UnitQNum, b:UnitQNum, t:f32) -> UnitQNum{ UnitQNum{q:QNum::slerp(a.q, b.q, t)} }
+    ///The function for normalized linear interpolation between rotations (see 'QNum::nlerp')
+    ///
+    ///Функция нормализованной линейной интерполяции между поворотами (см. 'QNum::nlerp')
+    pub fn nlerp(a:UnitQNum, b:UnitQNum, t:f32) -> UnitQNum{ UnitQNum{q:QNum::nlerp(a.q, b.q, t)} }
+    ///The function that returns the SQUAD control point for the key 'cur' (see 'QNum::squad_control')
+    ///
+    ///Функция, возвращающая контрольную точку SQUAD для ключа 'cur' (см. 'QNum::squad_control')
+    pub fn squad_control(prev:UnitQNum, cur:UnitQNum, next:UnitQNum) -> UnitQNum{ UnitQNum{q:QNum::squad_control(prev.q, cur.q, next.q)} }
+    ///The function for spherical cubic interpolation between the rotations 'q1' and 'q2' (see 'QNum::squad')
+    ///
+    ///Функция сферической кубической интерполяции между поворотами 'q1' и 'q2' (см. 'QNum::squad')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// let q: Vec<UnitQNum> = (0..4).map(|n| UnitQNum::make_from_a(0.4_f32*n as f32, z)).collect();
+    /// let s = UnitQNum::squad(q[0].clone(), q[1].clone(), q[2].clone(), q[3].clone(), 0.5_f32);
+    /// assert!((UnitQNum::angular_distance(s, q[1].clone())-0.2_f32).abs() < 0.0001);
+    /// ```
+    pub fn squad(q0:UnitQNum, q1:UnitQNum, q2:UnitQNum, q3:UnitQNum, t:f32) -> UnitQNum{ UnitQNum{q:QNum::squad(q0.q, q1.q, q2.q, q3.q, t)} }
+    ///The function that returns the weighted average of the rotations and the dispersion (see 'QNum::average')
+    ///
+    ///Функция, возвращающая взвешенное среднее поворотов и разброс (см. 'QNum::average')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// let us = [UnitQNum::make_from_a(0.1_f32, z), -UnitQNum::make_from_a(0.3_f32, z), UnitQNum::make_from_a(0.5_f32, z)];
+    /// let (mean, _) = UnitQNum::average(&us, &[]);
+    /// assert!(UnitQNum::angular_distance(mean, UnitQNum::make_from_a(0.3_f32, z)) < 0.001);
+    /// ```
+    pub fn average(us:&[UnitQNum], ws:&[f32]) -> (UnitQNum, f32){
+        let qs: Vec<QNum> = us.iter().map(|u| u.get_q()).collect();
+        let (q, disp) = QNum::average(&qs, ws);
+        (UnitQNum{q}, disp)
+    }
+    ///The function that returns the weighted geodesic (Karcher) mean of the rotations and the dispersion (see 'QNum::karcher_mean')
+    ///
+    ///Функция, возвращающая взвешенное геодезическое среднее (среднее Кархера) поворотов и разброс (см. 'QNum::karcher_mean')
+    pub fn karcher_mean(us:&[UnitQNum], ws:&[f32]) -> (UnitQNum, f32){
+        let qs: Vec<QNum> = us.iter().map(|u| u.get_q()).collect();
+        let (q, disp) = QNum::karcher_mean(&qs, ws);
+        (UnitQNum{q}, disp)
+    }
+    ///The method that returns the canonical sign of the quaternion of the rotation (see 'QNum::canonicalize')
+    ///
+    ///Метод, возвращающий канонический знак кватерниона поворота (см. 'QNum::canonicalize')
+    pub fn canonicalize(&self) -> UnitQNum{ UnitQNum{q:self.q.canonicalize()} }
+    ///The method that returns the quaternion of the rotation lying in the same hemisphere as 'reference' (see 'QNum::canonicalize_to')
+    ///
+    ///Метод, возвращающий кватернион поворота, лежащий в одной полусфере с 'reference' (см. 'QNum::canonicalize_to')
+    pub fn canonicalize_to(&self, reference:UnitQNum) -> UnitQNum{ UnitQNum{q:self.q.canonicalize_to(reference.q)} }
+    ///The function that enforces the sign continuity of the sequence of rotations 'us' in place (see 'QNum::unwrap')
+    ///
+    ///Функция, обеспечивающая на месте непрерывность знака последовательности поворотов 'us' (см. 'QNum::unwrap')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let x = (1_f32, 0_f32, 0_f32);
+    /// let mut us = [UnitQNum::make_from_a(0.1_f32, x), -UnitQNum::make_from_a(0.2_f32, x)];
+    /// UnitQNum::unwrap(&mut us);
+    /// assert!(us[1].get_q().dot(us[0].get_q()) > 0_f32);
+    /// ```
+    pub fn unwrap(us:&mut [UnitQNum]){
+        for n in 1..us.len() {
+            if us[n].q.dot(us[n-1].get_q()) < 0_f32 {
+                us[n] = -us[n].clone();
+            }
+        }
+    }
+    ///The method that returns the 3x3 rotation matrix (row by row)
+    ///
+    ///Метод, возвращающий матрицу поворота 3x3 (по строкам)
+    pub fn to_rotation_matrix(&self) -> [[f32; 3]; 3]{ self.q.to_rotation_matrix() }
+    ///The method that returns the 4x4 homogeneous transformation matrix (row by row)
+    ///
+    ///Метод, возвращающий однородную матрицу преобразования 4x4 (по строкам)
+    pub fn to_homogeneous(&self) -> [[f32; 4]; 4]{ self.q.to_homogeneous() }
+    ///The function that creates a rotation from a 3x3 rotation matrix (see 'QNum::from_rotation_matrix')
+    ///
+    ///Функция, создающая поворот из матрицы поворота 3x3 (см. 'QNum::from_rotation_matrix')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let u = UnitQNum::make_from_a(2_f32, (1_f32, 1_f32, 1_f32));
+    /// assert!(UnitQNum::from_rotation_matrix(u.to_rotation_matrix()).get_q().dot(u.get_q()) > 0.99999_f32);
+    /// ```
+    pub fn from_rotation_matrix(m:[[f32; 3]; 3]) -> UnitQNum{ UnitQNum{q:QNum::from_rotation_matrix(m)} }
+    ///The function that creates a rotation from Euler angles (see 'QNum::from_euler')
+    ///
+    ///Функция, создающая поворот из углов Эйлера (см. 'QNum::from_euler')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::{UnitQNum, EulerSeq, EulerAxes};
+    /// let u = UnitQNum::from_euler(EulerSeq::Extrinsic(EulerAxes::Zxz), 0.1_f32, 0.2_f32, 0.3_f32);
+    /// let (a, b, c) = u.to_euler(EulerSeq::Extrinsic(EulerAxes::Zxz));
+    /// assert!((a-0.1_f32).abs() < 0.00001 && (b-0.2_f32).abs() < 0.00001 && (c-0.3_f32).abs() < 0.00001);
+    /// ```
+    pub fn from_euler(seq:EulerSeq, a:f32, b:f32, c:f32) -> UnitQNum{ UnitQNum{q:QNum::from_euler(seq, a, b, c)} }
+    ///The method that returns Euler angles of the rotation (see 'QNum::to_euler')
+    ///
+    ///Метод, возвращающий углы Эйлера поворота (см. 'QNum::to_euler')
+    pub fn to_euler(&self, seq:EulerSeq) -> (f32, f32, f32){ self.q.to_euler(seq) }
+    ///The method that returns the angle and the unit axis of the rotation (see 'QNum::to_axis_angle')
+    ///
+    ///Метод, возвращающий угол и единичную ось поворота (см. 'QNum::to_axis_angle')
+    pub fn to_axis_angle(&self) -> (f32, (f32, f32, f32)){ self.q.to_axis_angle() }
+    ///The function that creates a rotation from the rotation vector (see 'QNum::from_rotation_vector')
+    ///
+    ///Функция, создающая поворот из вектора поворота (см. 'QNum::from_rotation_vector')
+    pub fn from_rotation_vector(v:(f32, f32, f32)) -> UnitQNum{ UnitQNum{q:QNum::from_rotation_vector(v)} }
+    ///The method that returns the rotation vector (see 'QNum::to_rotation_vector')
+    ///
+    ///Метод, возвращающий вектор поворота (см. 'QNum::to_rotation_vector')
+    pub fn to_rotation_vector(&self) -> (f32, f32, f32){ self.q.to_rotation_vector() }
+    ///The function that creates the shortest-arc rotation between the vectors 'a' and 'b' (see 'QNum::from_two_vectors')
+    ///
+    ///Функция, создающая поворот по кратчайшей дуге между векторами 'a' и 'b' (см. 'QNum::from_two_vectors')
+    pub fn from_two_vectors(a:(f32, f32, f32), b:(f32, f32, f32)) -> UnitQNum{ UnitQNum{q:QNum::from_two_vectors(a, b)} }
+    ///The function that creates the orientation looking in the direction 'forward' (see 'QNum::look_rotation')
+    ///
+    ///Функция, создающая ориентацию, смотрящую в направлении 'forward' (см. 'QNum::look_rotation')
+    pub fn look_rotation(forward:(f32, f32, f32), up:(f32, f32, f32)) -> UnitQNum{ UnitQNum{q:QNum::look_rotation(forward, up)} }
+    ///The method that advances the orientation by the body-frame angular velocity 'w' during the time 'dt' (see 'QNum::integrate_exp')
+    ///
+    ///Метод, продвигающий ориентацию на угловую скорость 'w' в связанной системе координат за время 'dt' (см. 'QNum::integrate_exp')
+    pub fn integrate_exp(&self, w:(f32, f32, f32), dt:f32) -> UnitQNum{ UnitQNum{q:self.q.integrate_exp(w, dt)} }
+    ///The method that advances the orientation by the angular velocity changing linearly from 'w0' to 'w1' during the time 'dt' (see 'QNum::integrate_rk4')
+    ///
+    ///Метод, продвигающий ориентацию на угловую скорость, линейно меняющуюся от 'w0' до 'w1' за время 'dt' (см. 'QNum::integrate_rk4')
+    pub fn integrate_rk4(&self, w0:(f32, f32, f32), w1:(f32, f32, f32), dt:f32) -> UnitQNum{ UnitQNum{q:self.q.integrate_rk4(w0, w1, dt)} }
+    ///The method that advances the orientation by the angular increments 'ds' with the coning correction (see 'QNum::integrate_coning')
+    ///
+    ///Метод, продвигающий ориентацию на приращения угла 'ds' с конической поправкой (см. 'QNum::integrate_coning')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let d = (0_f32, 0.01_f32, 0_f32);
+    /// let mut u = UnitQNum::make_identity();
+    /// for _ in 0..50 {
+    ///     u = u.integrate_coning(&[d, d]);
+    /// }
+    /// assert!(UnitQNum::angular_distance(u, UnitQNum::make_from_a(1_f32, (0_f32, 1_f32, 0_f32))) < 0.001);
+    /// ```
+    pub fn integrate_coning(&self, ds:&[(f32, f32, f32)]) -> UnitQNum{ UnitQNum{q:self.q.integrate_coning(ds)} }
+    ///The function that returns the geodesic distance between the rotations (see 'QNum::angular_distance')
+    ///
+    ///Функция, возвращающая геодезическое расстояние между поворотами (см. 'QNum::angular_distance')
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let a = UnitQNum::make_from_a(0.5_f32, (1_f32, 0_f32, 0_f32));
+    /// let b = UnitQNum::make_from_a(-0.5_f32, (1_f32, 0_f32, 0_f32));
+    /// assert!((UnitQNum::angular_distance(a, b)-1_f32).abs() < 0.00001);
+    /// ```
+    pub fn angular_distance(a:UnitQNum, b:UnitQNum) -> f32{ QNum::angular_distance(a.q, b.q) }
+    ///The function that returns the chordal distance between the rotations (see 'QNum::chordal_distance')
+    ///
+    ///Функция, возвращающая хордовое расстояние между поворотами (см. 'QNum::chordal_distance')
+    pub fn chordal_distance(a:UnitQNum, b:UnitQNum) -> f32{ QNum::chordal_distance(a.q, b.q) }
+    ///The function that returns the rotation error of the estimation relative to the true rotation (see 'QNum::rotation_error')
+    ///
+    ///Функция, возвращающая ошибку поворота оценки относительно истинного поворота (см. 'QNum::rotation_error')
+    pub fn rotation_error(est:UnitQNum, truth:UnitQNum) -> (f32, (f32, f32, f32)){ QNum::rotation_error(est.q, truth.q) }
+    ///The function that returns the squared angular distance (see 'QNum::geodesic_loss')
+    ///
+    ///Функция, возвращающая квадрат углового расстояния (см. 'QNum::geodesic_loss')
+    pub fn geodesic_loss(est:UnitQNum, truth:UnitQNum) -> f32{ QNum::geodesic_loss(est.q, truth.q) }
+    ///The function that returns the chordal loss (see 'QNum::chordal_loss')
+    ///
+    ///Функция, возвращающая хордовую функцию потерь (см. 'QNum::chordal_loss')
+    pub fn chordal_loss(est:UnitQNum, truth:UnitQNum) -> f32{ QNum::chordal_loss(est.q, truth.q) }
+    ///The method that splits the rotation into the swing and the twist about the axis 'axis' (see 'QNum::swing_twist')
+    ///
+    ///Метод, разделяющий поворот на качание и закрутку вокруг оси 'axis' (см. 'QNum::swing_twist')
+    pub fn swing_twist(&self, axis:(f32, f32, f32)) -> (UnitQNum, UnitQNum){
+        let (s, t) = self.q.swing_twist(axis);
+        (UnitQNum{q:s}, UnitQNum{q:t})
+    }
+    ///The function that composes the rotation from the swing and the twist (see 'QNum::from_swing_twist')
+    ///
+    ///Функция, составляющая поворот из качания и закрутки (см. 'QNum::from_swing_twist')
+    pub fn from_swing_twist(swing:UnitQNum, twist:UnitQNum) -> UnitQNum{ swing.mult_u(twist) }
+}
+
+impl PartialEq for UnitQNum{
+    fn eq(&self, other: &Self) -> bool {
+        self.q == other.q
+    }
+}
+impl Neg for UnitQNum {
+    type Output = Self;
+    ///Redefined negative operator. The result is the same rotation
+    ///
+    ///Переопределенный оператор отрицательного значения. Результат задает тот же поворот
+    ///
+    /// # Example
+    ///```
+    /// use tmn::quaternion::UnitQNum;
+    /// let u = -UnitQNum::make_identity();
+    /// assert_eq!((-1_f32, -0_f32, -0_f32, -0_f32), u.get());
+    /// ```
+    fn neg(self) -> Self::Output {
+        UnitQNum{q:-self.q}
+    }
+}
@@ -0,0 +1,183 @@
+//!Seeded random sampling of rotations and complex numbers
+//!
+//!Генерация случайных поворотов и комплексных чисел с заданным зерном
+use std::f32::consts::PI;
+use crate::complex::CNum;
+use crate::quaternion::{QNum, UnitQNum};
+
+///Small seedable pseudo-random number generator (SplitMix64). The same seed always gives the same sequence
+///
+///Небольшой генератор псевдослучайных чисел с зерном (SplitMix64). Одно и то же зерно всегда дает одну и ту же последовательность
+pub struct Rng{
+    state:u64
+}
+
+impl Rng {
+    ///The function for creating a generator from the seed
+    ///
+    ///Функция для создания генератора из зерна
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut a = Rng::make(42);
+    /// let mut b = Rng::make(42);
+    /// assert_eq!(a.next_u64(), b.next_u64());
+    /// ```
+    pub fn make(seed:u64) -> Self{ Rng{state:seed} }
+    ///The method that returns the next random 64-bit integer
+    ///
+    ///Метод, возвращающий следующее случайное 64-битное целое число
+    pub fn next_u64(&mut self) -> u64{
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+    ///The method that returns a random number uniformly distributed in [0, 1)
+    ///
+    ///Метод, возвращающий случайное число, равномерно распределенное в [0, 1)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(1);
+    /// let mean: f32 = (0..10000).map(|_| rng.next_f32()).sum::<f32>()/10000_f32;
+    /// assert!((mean-0.5_f32).abs() < 0.02);
+    /// ```
+    pub fn next_f32(&mut self) -> f32{
+        (self.next_u64() >> 40) as f32/(1_u64 << 24) as f32
+    }
+    ///The method that returns a random number uniformly distributed in [a, b)
+    ///
+    ///Метод, возвращающий случайное число, равномерно распределенное в [a, b)
+    pub fn uniform(&mut self, a:f32, b:f32) -> f32{ a + (b - a)*self.next_f32() }
+    ///The method that returns a random number with the standard normal distribution (the Box-Muller method)
+    ///
+    ///Метод, возвращающий случайное число со стандартным нормальным распределением (метод Бокса-Мюллера)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(2);
+    /// let xs: Vec<f32> = (0..20000).map(|_| rng.gauss()).collect();
+    /// let mean = xs.iter().sum::<f32>()/20000_f32;
+    /// let var = xs.iter().map(|x| x*x).sum::<f32>()/20000_f32;
+    /// assert!(mean.abs() < 0.03 && (var-1_f32).abs() < 0.03);
+    /// ```
+    pub fn gauss(&mut self) -> f32{
+        let u = 1_f32 - self.next_f32();
+        let v = self.next_f32();
+        (-2_f32*u.ln()).powf(0.5)*(2_f32*PI*v).cos()
+    }
+    ///The method that returns a random unit vector uniformly distributed on the sphere
+    ///
+    ///Метод, возвращающий случайный единичный вектор, равномерно распределенный по сфере
+    pub fn unit_vector(&mut self) -> (f32, f32, f32){
+        let z = self.uniform(-1_f32, 1_f32);
+        let a = self.uniform(0_f32, 2_f32*PI);
+        let s = (1_f32 - z*z).max(0_f32).powf(0.5);
+        (s*a.cos(), s*a.sin(), z)
+    }
+    ///The method that returns a random rotation uniformly distributed over all rotations (Shoemake's method)
+    ///
+    ///Метод, возвращающий случайный поворот, равномерно распределенный по всем поворотам (метод Шумейка)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(3);
+    /// //Для равномерного распределения средний квадрат каждого коэффициента равен 1/4
+    /// let mut s = [0_f32; 4];
+    /// for _ in 0..20000 {
+    ///     let (r, i, j, k) = rng.unit_quaternion().get();
+    ///     for (a, v) in s.iter_mut().zip([r, i, j, k]) {
+    ///         *a += v*v/20000_f32;
+    ///     }
+    /// }
+    /// assert!(s.iter().all(|a| (a-0.25_f32).abs() < 0.01));
+    /// ```
+    pub fn unit_quaternion(&mut self) -> UnitQNum{
+        let u1 = self.next_f32();
+        let u2 = self.uniform(0_f32, 2_f32*PI);
+        let u3 = self.uniform(0_f32, 2_f32*PI);
+        let a = (1_f32 - u1).powf(0.5);
+        let b = u1.powf(0.5);
+        UnitQNum::make(QNum::make_from_r(b*u3.cos(), a*u2.sin(), a*u2.cos(), b*u3.sin()))
+    }
+    ///The method that returns a random rotation uniformly distributed among the rotations which differ from 'reference' by no more than the angle 'max_ang' (in radians)
+    ///
+    ///Метод, возвращающий случайный поворот, равномерно распределенный среди поворотов, отличающихся от 'reference' не больше чем на угол 'max_ang' (в радианах)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// use tmn::quaternion::UnitQNum;
+    /// let mut rng = Rng::make(4);
+    /// let reference = UnitQNum::make_from_a(1_f32, (1_f32, 2_f32, 3_f32));
+    /// for _ in 0..1000 {
+    ///     let q = rng.quaternion_near(reference.clone(), 0.1_f32);
+    ///     assert!(UnitQNum::angular_distance(q, reference.clone()) <= 0.1001_f32);
+    /// }
+    /// ```
+    pub fn quaternion_near(&mut self, reference:UnitQNum, max_ang:f32) -> UnitQNum{
+        let max_ang = max_ang.clamp(0_f32, PI);
+        //Плотность угла равномерного распределения поворотов пропорциональна 1 - cos(ang)
+        let top = 1_f32 - max_ang.cos();
+        let ang = loop {
+            let ang = self.uniform(0_f32, max_ang);
+            if top == 0_f32 || self.next_f32()*top <= 1_f32 - ang.cos() {
+                break ang;
+            }
+        };
+        reference.mult_u(UnitQNum::make_from_a(ang, self.unit_vector()))
+    }
+    ///The method that returns a random complex number uniformly distributed on the unit circle
+    ///
+    ///Метод, возвращающий случайное комплексное число, равномерно распределенное по единичной окружности
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(5);
+    /// assert!((rng.unit_complex().modl()-1_f32).abs() < 0.000001);
+    /// ```
+    pub fn unit_complex(&mut self) -> CNum{
+        let a = self.uniform(-PI, PI);
+        CNum::make(a.cos(), a.sin())
+    }
+    ///The method that returns a random complex number uniformly distributed in the disk of the radius 'radius' centered at zero
+    ///
+    ///Метод, возвращающий случайное комплексное число, равномерно распределенное в круге радиуса 'radius' с центром в нуле
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(6);
+    /// let cs: Vec<f32> = (0..20000).map(|_| rng.complex_in_disk(2_f32).modl()).collect();
+    /// assert!(cs.iter().all(|m| *m <= 2_f32));
+    /// //Половина точек лежит внутри круга радиуса 2/sqrt(2)
+    /// let inner = cs.iter().filter(|m| **m < 2_f32/(2_f32).powf(0.5)).count() as f32/20000_f32;
+    /// assert!((inner-0.5_f32).abs() < 0.01);
+    /// ```
+    pub fn complex_in_disk(&mut self, radius:f32) -> CNum{
+        let r = radius*self.next_f32().powf(0.5);
+        self.unit_complex().mult_r(r)
+    }
+    ///The method that returns a circularly-symmetric complex Gaussian number with the variance 'sigma'², that is the real and imaginary parts are independent with the variance 'sigma'²/2
+    ///
+    ///Метод, возвращающий циркулярно-симметричное комплексное гауссовское число с дисперсией 'sigma'², то есть действительная и мнимая части независимы с дисперсией 'sigma'²/2
+    ///
+    /// # Example
+    ///```
+    /// use tmn::random::Rng;
+    /// let mut rng = Rng::make(7);
+    /// let var = (0..20000).map(|_| rng.complex_gauss(3_f32).modl().powf(2_f32)).sum::<f32>()/20000_f32;
+    /// assert!((var-9_f32).abs() < 0.3);
+    /// ```
+    pub fn complex_gauss(&mut self, sigma:f32) -> CNum{
+        let s = sigma/(2_f32).powf(0.5);
+        CNum::make(s*self.gauss(), s*self.gauss())
+    }
+}
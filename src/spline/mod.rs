@@ -0,0 +1,392 @@
+//!Quaternion splines and keyframe animation tracks
+//!
+//!Кватернионные сплайны и дорожки анимации по ключевым кадрам
+use crate::linalg;
+use crate::quaternion::{QNum, UnitQNum};
+
+///The kind of the spline. Catmull-Rom and Kochanek-Bartels splines pass through the keyframes, the cumulative B-spline only approximates them, but has a continuous angular acceleration
+///
+///Вид сплайна. Сплайны Катмулла-Рома и Кочанека-Бартелса проходят через ключевые кадры, кумулятивный B-сплайн только приближает их, но имеет непрерывное угловое ускорение
+pub enum SplineKind{
+    CatmullRom,
+    KochanekBartels{tension:f32, continuity:f32, bias:f32},
+    BSpline
+}
+
+impl SplineKind {
+    ///The method for cloning the spline kind
+    ///
+    ///Метод для клонирования вида сплайна
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> SplineKind{
+        match self {
+            SplineKind::CatmullRom => SplineKind::CatmullRom,
+            SplineKind::KochanekBartels{tension, continuity, bias} => SplineKind::KochanekBartels{tension:*tension, continuity:*continuity, bias:*bias},
+            SplineKind::BSpline => SplineKind::BSpline
+        }
+    }
+}
+
+///The structure storing the keyframe: the time and the orientation
+///
+///Структура, хранящая ключевой кадр: время и ориентацию
+pub struct Keyframe{
+    time:f32,
+    q:UnitQNum
+}
+
+impl Keyframe {
+    ///The function for creating a keyframe
+    ///
+    ///Функция для создания ключевого кадра
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::Keyframe;
+    /// use tmn::quaternion::UnitQNum;
+    /// let k = Keyframe::make(1.5_f32, UnitQNum::make_identity());
+    /// assert_eq!(1.5_f32, k.get().0);
+    /// ```
+    pub fn make(time:f32, q:UnitQNum) -> Self{ Keyframe{time, q} }
+    ///The method that returns the time and the orientation
+    ///
+    ///Метод, возвращающий время и ориентацию
+    pub fn get(&self) -> (f32, UnitQNum){ (self.time, self.q.clone()) }
+}
+
+///Animation track: timed keyframes interpolated by a quaternion spline
+///
+///Дорожка анимации: ключевые кадры во времени, интерполируемые кватернионным сплайном
+pub struct Track{
+    keys:Vec<(f32, QNum)>,
+    kind:SplineKind,
+    looping:bool
+}
+
+//Логарифм относительного поворота a*·b
+fn log_rel(a:&QNum, b:&QNum) -> QNum{
+    let (_, i, j, k) = a.conj().mult_q(b.canonicalize_to(a.clone())).ln().get();
+    QNum::make_from_r(0_f32, i, j, k)
+}
+
+impl Track {
+    ///The function for creating a track from the keyframes 'keys', the spline kind 'kind' and the looping flag. The keyframes are sorted by time and their signs are made continuous. For a looping track the last keyframe closes the loop and should have the same orientation as the first one
+    ///
+    ///Функция для создания дорожки из ключевых кадров 'keys', вида сплайна 'kind' и признака зацикливания. Ключевые кадры упорядочиваются по времени, и их знаки делаются непрерывными. Для зацикленной дорожки последний ключевой кадр замыкает цикл и должен иметь ту же ориентацию, что и первый
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// let keys = vec![
+    ///     Keyframe::make(1_f32, UnitQNum::make_from_a(1_f32, (0_f32, 0_f32, 1_f32))),
+    ///     Keyframe::make(0_f32, UnitQNum::make_identity())
+    /// ];
+    /// let track = Track::make(keys, SplineKind::CatmullRom, false);
+    /// assert_eq!((0_f32, 1_f32), track.get_range());
+    /// assert_eq!(2, track.get_keys().len());
+    /// ```
+    pub fn make(keys:Vec<Keyframe>, kind:SplineKind, looping:bool) -> Self{
+        assert!(!keys.is_empty());
+        let mut keys: Vec<(f32, QNum)> = keys.into_iter().map(|k| (k.time, k.q.get_q())).collect();
+        keys.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for n in 1..keys.len() {
+            if keys[n].1.dot(keys[n-1].1.clone()) < 0_f32 {
+                keys[n].1 = -keys[n].1.clone();
+            }
+        }
+        Track{keys, kind, looping}
+    }
+    ///The method that returns the keyframes
+    ///
+    ///Метод, возвращающий ключевые кадры
+    pub fn get_keys(&self) -> Vec<Keyframe>{
+        self.keys.iter().map(|(t, q)| Keyframe::make(*t, UnitQNum::make(q.clone()))).collect()
+    }
+    ///The method that returns the times of the first and the last keyframes
+    ///
+    ///Метод, возвращающий времена первого и последнего ключевых кадров
+    pub fn get_range(&self) -> (f32, f32){ (self.keys[0].0, self.keys[self.keys.len()-1].0) }
+    //Индекс ключа с учетом зацикливания или ограничения по краям
+    fn index(&self, i:isize) -> usize{
+        let n = self.keys.len() as isize;
+        if self.looping && n > 1 {
+            (i.rem_euclid(n - 1)) as usize
+        } else {
+            i.clamp(0, n - 1) as usize
+        }
+    }
+    //Длительность интервала от ключа i до следующего
+    fn interval(&self, i:isize) -> f32{
+        let n = self.keys.len() as isize;
+        if !self.looping && (i < 0 || i >= n - 1) {
+            return 0_f32;
+        }
+        let a = self.index(i);
+        self.keys[a+1].0 - self.keys[a].0
+    }
+    //Ключ i с учетом знака относительно ключа 'near'
+    fn key(&self, i:isize, near:&QNum) -> QNum{
+        self.keys[self.index(i)].1.canonicalize_to(near.clone())
+    }
+    //Контрольные точки Кочанека-Бартелса (входящая и исходящая) для ключа i
+    fn controls(&self, i:isize, t:f32, c:f32, b:f32) -> (QNum, QNum){
+        let q = self.keys[self.index(i)].1.clone();
+        let a_log = log_rel(&q, &self.key(i + 1, &q));
+        let b_log = log_rel(&q, &self.key(i - 1, &q));
+        //Поправка касательных для неравномерных интервалов
+        let (d0, d1) = (self.interval(i - 1), self.interval(i));
+        let (d0, d1) = if d0 == 0_f32 { (d1, d1) } else if d1 == 0_f32 { (d0, d0) } else { (d0, d1) };
+        let (k_in, k_out) = if d0 + d1 == 0_f32 { (1_f32, 1_f32) } else { (2_f32*d0/(d0 + d1), 2_f32*d1/(d0 + d1)) };
+        let ts = b_log.mult_r(-(1_f32 - t)*(1_f32 + c)*(1_f32 + b)/2_f32).add_q(a_log.mult_r((1_f32 - t)*(1_f32 - c)*(1_f32 - b)/2_f32)).mult_r(k_out);
+        let td = b_log.mult_r(-(1_f32 - t)*(1_f32 - c)*(1_f32 + b)/2_f32).add_q(a_log.mult_r((1_f32 - t)*(1_f32 + c)*(1_f32 - b)/2_f32)).mult_r(k_in);
+        let s_in = q.mult_q(td.add_q(b_log).mult_r(-0.5_f32).exp());
+        let s_out = q.mult_q(ts.add_q(-a_log).mult_r(0.5_f32).exp());
+        (s_in, s_out)
+    }
+    //Номер сегмента и локальный параметр u в [0, 1] для времени 'time'
+    fn locate(&self, time:f32) -> (isize, f32){
+        let (t0, t1) = self.get_range();
+        let n = self.keys.len();
+        let time = if self.looping && t1 > t0 {
+            t0 + (time - t0).rem_euclid(t1 - t0)
+        } else {
+            time.clamp(t0, t1)
+        };
+        let seg = (self.keys.partition_point(|k| k.0 <= time).max(1) - 1).min(n.max(2) - 2);
+        let d = self.keys[seg+1].0 - self.keys[seg].0;
+        let u = if d > 0_f32 { ((time - self.keys[seg].0)/d).clamp(0_f32, 1_f32) } else { 0_f32 };
+        (seg as isize, u)
+    }
+    ///The method that returns the orientation at the time 'time'. Outside the range of keyframes the time is clamped, or wrapped for a looping track
+    ///
+    ///Метод, возвращающий ориентацию в момент времени 'time'. Вне диапазона ключевых кадров время ограничивается, а для зацикленной дорожки - заворачивается
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// let z = (0_f32, 0_f32, 1_f32);
+    /// let keys: Vec<_> = (0..5).map(|n| Keyframe::make(n as f32, UnitQNum::make_from_a(0.5_f32*n as f32, z))).collect();
+    /// let track = Track::make(keys, SplineKind::CatmullRom, false);
+    /// //Сплайн проходит через ключевые кадры
+    /// let (ang, _) = track.eval(2_f32).to_axis_angle();
+    /// assert!((ang-1_f32).abs() < 0.00001);
+    /// //Равномерное вращение воспроизводится между ключами
+    /// let (ang, _) = track.eval(2.3_f32).to_axis_angle();
+    /// assert!((ang-1.15_f32).abs() < 0.0001);
+    /// ```
+    /// ```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// let keys = vec![
+    ///     Keyframe::make(0_f32, UnitQNum::make_identity()),
+    ///     Keyframe::make(1_f32, UnitQNum::make_from_a(1_f32, (1_f32, 0_f32, 0_f32))),
+    ///     Keyframe::make(3_f32, UnitQNum::make_from_a(1_f32, (0_f32, 1_f32, 0_f32))),
+    ///     Keyframe::make(4_f32, UnitQNum::make_identity())
+    /// ];
+    /// let kb = SplineKind::KochanekBartels{tension:0.5_f32, continuity:0_f32, bias:0.2_f32};
+    /// let track = Track::make(keys, kb, true);
+    /// let key = UnitQNum::make_from_a(1_f32, (0_f32, 1_f32, 0_f32)).get_q();
+    /// assert!(track.eval(3_f32).get_q().dot(key.clone()).abs() > 0.99999_f32);
+    /// //Зацикливание
+    /// assert!(track.eval(7_f32).get_q().dot(key).abs() > 0.99999_f32);
+    /// ```
+    pub fn eval(&self, time:f32) -> UnitQNum{
+        if self.keys.len() == 1 {
+            return UnitQNum::make(self.keys[0].1.clone());
+        }
+        let (i, u) = self.locate(time);
+        let q0 = self.keys[i as usize].1.clone();
+        match &self.kind {
+            SplineKind::BSpline => {
+                //Кумулятивный B-сплайн Кима-Кима-Шина
+                let b1 = (5_f32 + 3_f32*u - 3_f32*u*u + u*u*u)/6_f32;
+                let b2 = (1_f32 + 3_f32*u + 3_f32*u*u - 2_f32*u*u*u)/6_f32;
+                let b3 = u*u*u/6_f32;
+                let mut prev = self.key(i - 1, &q0);
+                let mut q = prev.clone();
+                for (j, bj) in [b1, b2, b3].into_iter().enumerate() {
+                    let next = self.key(i + j as isize, &prev);
+                    q = q.mult_q(log_rel(&prev, &next).mult_r(bj).exp());
+                    prev = next;
+                }
+                UnitQNum::make(q)
+            },
+            kind => {
+                let (t, c, b) = match kind {
+                    SplineKind::KochanekBartels{tension, continuity, bias} => (*tension, *continuity, *bias),
+                    _ => (0_f32, 0_f32, 0_f32)
+                };
+                let q1 = self.key(i + 1, &q0);
+                let (_, s0) = self.controls(i, t, c, b);
+                let (s1, _) = self.controls(i + 1, t, c, b);
+                let s1 = s1.canonicalize_to(q1.clone());
+                let h = 2_f32*u*(1_f32 - u);
+                UnitQNum::make(QNum::slerp(QNum::slerp(q0, q1, u), QNum::slerp(s0, s1, u), h))
+            }
+        }
+    }
+    //Шаг численного дифференцирования
+    fn step(&self) -> f32{
+        let (t0, t1) = self.get_range();
+        ((t1 - t0)/self.keys.len() as f32).max(0.000001_f32)
+    }
+    //Моменты численного дифференцирования time ± h; без зацикливания они ограничиваются диапазоном ключей (односторонняя разность на краях)
+    fn diff_times(&self, time:f32, h:f32) -> (f32, f32){
+        if self.looping {
+            return (time - h, time + h);
+        }
+        let (t0, t1) = self.get_range();
+        ((time - h).clamp(t0, t1), (time + h).clamp(t0, t1))
+    }
+    ///The method that returns the angular velocity (in the body frame, in radians per unit of time) at the time 'time'. It is calculated by numerical differentiation; at the ends of a non-looping track the one-sided difference is used. Near the ends of a non-looping track the spline itself slows down, because the outermost keys are repeated
+    ///
+    ///Метод, возвращающий угловую скорость (в связанной системе координат, в радианах на единицу времени) в момент времени 'time'. Вычисляется численным дифференцированием; на краях незацикленной дорожки используется односторонняя разность. Вблизи краев незацикленной дорожки сам сплайн замедляется, так как крайние ключи повторяются
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// let w = (0.2_f32, -0.4_f32, 0.4_f32);
+    /// let keys: Vec<_> = (0..6).map(|n| Keyframe::make(n as f32, UnitQNum::from_rotation_vector((0.2_f32*n as f32, -0.4_f32*n as f32, 0.4_f32*n as f32)))).collect();
+    /// let track = Track::make(keys, SplineKind::BSpline, false);
+    /// let (x, y, z) = track.angular_velocity(2.5_f32);
+    /// assert!((x-w.0).abs() < 0.001 && (y-w.1).abs() < 0.001 && (z-w.2).abs() < 0.001);
+    /// //На краях дорожки оценка скорости непрерывна
+    /// for (t, inner) in [(0_f32, 0.01_f32), (5_f32, 4.99_f32)] {
+    ///     let (x, y, z) = track.angular_velocity(t);
+    ///     let (xi, yi, zi) = track.angular_velocity(inner);
+    ///     assert!((x-xi).abs() < 0.005 && (y-yi).abs() < 0.005 && (z-zi).abs() < 0.005);
+    /// }
+    /// ```
+    pub fn angular_velocity(&self, time:f32) -> (f32, f32, f32){
+        let (ta, tb) = self.diff_times(time, 0.001_f32*self.step());
+        if tb <= ta {
+            return (0_f32, 0_f32, 0_f32);
+        }
+        let a = self.eval(ta);
+        let b = self.eval(tb);
+        linalg::v_mult_r(a.inv().mult_u(b).to_rotation_vector(), 1_f32/(tb - ta))
+    }
+    ///The method that returns the angular acceleration (in the body frame) at the time 'time'. It is calculated by numerical differentiation of the angular velocity (one-sided at the ends of a non-looping track)
+    ///
+    ///Метод, возвращающий угловое ускорение (в связанной системе координат) в момент времени 'time'. Вычисляется численным дифференцированием угловой скорости (односторонним на краях незацикленной дорожки)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// //Угол поворота вокруг оси z растет как t²/2
+    /// let keys: Vec<_> = (0..9).map(|n| {
+    ///     let t = n as f32*0.5_f32;
+    ///     Keyframe::make(t, UnitQNum::make_from_a(t*t/2_f32, (0_f32, 0_f32, 1_f32)))
+    /// }).collect();
+    /// let track = Track::make(keys, SplineKind::CatmullRom, false);
+    /// let (x, y, z) = track.angular_acceleration(2_f32);
+    /// assert!(x.abs() < 0.01 && y.abs() < 0.01 && (z-1_f32).abs() < 0.05);
+    /// ```
+    pub fn angular_acceleration(&self, time:f32) -> (f32, f32, f32){
+        let (ta, tb) = self.diff_times(time, 0.02_f32*self.step());
+        if tb <= ta {
+            return (0_f32, 0_f32, 0_f32);
+        }
+        let a = self.angular_velocity(ta);
+        let b = self.angular_velocity(tb);
+        linalg::v_mult_r(linalg::v_add(b, linalg::v_mult_r(a, -1_f32)), 1_f32/(tb - ta))
+    }
+    ///The method that returns the track resampled with the time step 'dt' over the range of keyframes. The last keyframe is always kept
+    ///
+    ///Метод, возвращающий дорожку, передискретизированную с шагом по времени 'dt' на диапазоне ключевых кадров. Последний ключевой кадр всегда сохраняется
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// let keys = vec![
+    ///     Keyframe::make(0_f32, UnitQNum::make_identity()),
+    ///     Keyframe::make(1_f32, UnitQNum::make_from_a(1_f32, (1_f32, 1_f32, 0_f32)))
+    /// ];
+    /// let track = Track::make(keys, SplineKind::CatmullRom, false);
+    /// let r = track.resample(0.25_f32);
+    /// assert_eq!(5, r.get_keys().len());
+    /// assert!(r.eval(0.5_f32).get_q().dot(track.eval(0.5_f32).get_q()) > 0.99999_f32);
+    /// ```
+    pub fn resample(&self, dt:f32) -> Track{
+        assert!(dt > 0_f32);
+        let (t0, t1) = self.get_range();
+        let mut keys = Vec::new();
+        let mut n = 0;
+        loop {
+            let t = t0 + n as f32*dt;
+            if t >= t1 - dt*0.001_f32 {
+                break;
+            }
+            keys.push(Keyframe::make(t, self.eval(t)));
+            n += 1;
+        }
+        keys.push(Keyframe::make(t1, self.eval(t1)));
+        Track::make(keys, self.kind.clone(), self.looping)
+    }
+    ///The method that returns the track with a reduced subset of keyframes (chosen greedily) such that the angular difference from the original track does not exceed 'tol' (in radians) at the times of the original keyframes and at 7 evenly spaced times inside every interval between them. The difference is not checked between these samples, so it can slightly exceed 'tol' there. The first and the last keyframes are always kept
+    ///
+    ///Метод, возвращающий дорожку с сокращенным подмножеством ключевых кадров (выбранным жадно), таким, что угловое отличие от исходной дорожки не превышает 'tol' (в радианах) в моменты исходных ключевых кадров и в 7 равномерно расположенных моментах внутри каждого интервала между ними. Между этими отсчетами отличие не проверяется, поэтому там оно может немного превышать 'tol'. Первый и последний ключевые кадры всегда сохраняются
+    ///
+    /// # Example
+    ///```
+    /// use tmn::spline::{Keyframe, SplineKind, Track};
+    /// use tmn::quaternion::UnitQNum;
+    /// //Равномерное вращение с одним резким поворотом
+    /// let keys: Vec<_> = (0..21).map(|n| {
+    ///     let t = n as f32*0.1_f32;
+    ///     let ang = if n < 10 { 0.1_f32*t } else { 0.1_f32*t + 1_f32 };
+    ///     Keyframe::make(t, UnitQNum::make_from_a(ang, (0_f32, 1_f32, 0_f32)))
+    /// }).collect();
+    /// let track = Track::make(keys, SplineKind::CatmullRom, false);
+    /// let reduced = track.reduce(0.01_f32);
+    /// assert!(reduced.get_keys().len() < 10);
+    /// for n in 0..=40 {
+    ///     let t = n as f32*0.05_f32;
+    ///     assert!(UnitQNum::angular_distance(reduced.eval(t), track.eval(t)) <= 0.0101_f32);
+    /// }
+    /// ```
+    pub fn reduce(&self, tol:f32) -> Track{
+        let n = self.keys.len();
+        let mut kept = vec![false; n];
+        kept[0] = true;
+        kept[n-1] = true;
+        //Контрольные моменты: ключевые кадры и 7 равномерных отсчетов внутри каждого интервала между ними
+        let mut checks: Vec<f32> = Vec::new();
+        for k in 0..n {
+            checks.push(self.keys[k].0);
+            if k + 1 < n {
+                let (t0, t1) = (self.keys[k].0, self.keys[k+1].0);
+                checks.extend((1..8).map(|s| t0 + (t1 - t0)*s as f32/8_f32));
+            }
+        }
+        let expected: Vec<QNum> = checks.iter().map(|t| self.eval(*t).get_q()).collect();
+        loop {
+            let keys: Vec<Keyframe> = (0..n).filter(|k| kept[*k])
+                .map(|k| Keyframe::make(self.keys[k].0, UnitQNum::make(self.keys[k].1.clone()))).collect();
+            let track = Track::make(keys, self.kind.clone(), self.looping);
+            let mut worst = (0_f32, 0_usize);
+            for (c, (t, e)) in checks.iter().zip(&expected).enumerate() {
+                let d = QNum::angular_distance(track.eval(*t).get_q(), e.clone());
+                if d > worst.0 {
+                    worst = (d, c);
+                }
+            }
+            if worst.0 <= tol {
+                return track;
+            }
+            //Добавляется ближайший к худшему моменту неиспользованный ключевой кадр
+            let t = checks[worst.1];
+            let best = (0..n).filter(|k| !kept[*k])
+                .min_by(|a, b| (self.keys[*a].0 - t).abs().total_cmp(&(self.keys[*b].0 - t).abs()));
+            match best {
+                Some(k) => kept[k] = true,
+                None => return track
+            }
+        }
+    }
+}
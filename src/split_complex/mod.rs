@@ -0,0 +1,265 @@
+//!Split-complex (hyperbolic) numbers
+
+
+use std::ops::Neg;
+use crate::cassette::cassette;
+
+///Structure for storing split-complex numbers a + b·j, where j² = +1
+///
+/// Структура для хранения расщепляемых комплексных чисел a + b·j, где j² = +1
+pub struct SNum {
+    r:f32,
+    j:f32
+}
+
+pub const R:u8 = 1;
+pub const J:u8 = 2;
+
+impl SNum {
+    ///The function for creating a split-complex number with zero coefficients
+    ///
+    ///Функция для создания расщепляемого комплексного числа с нулевыми коэффициентами
+    ///
+    /// # Example
+    ///
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// let a = SNum::make_zero();
+    /// assert!(SNum::make(0_f32, 0_f32)==a);
+    /// ```
+    pub fn make_zero()->Self{
+        Self{
+            r:0_f32,
+            j:0_f32
+        }
+    }
+    ///The function for creating a split-complex number from the real and hyperbolic parts
+    ///
+    ///Функция для создания расщепляемого комплексного числа из действительной и гиперболической части
+    ///
+    /// # Example
+    ///
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// let a = SNum::make(4_f32, -2_f32);
+    /// assert_eq!((4_f32, -2_f32), a.get());
+    /// ```
+    pub fn make(r:f32, j:f32)->Self{ Self{r, j} }
+    ///The function that creates a split-complex number from the hyperbolic polar form rho·(cosh(phi) + j·sinh(phi))
+    ///
+    ///Функция, создающая расщепляемое комплексное число из гиперболической полярной формы rho·(cosh(phi) + j·sinh(phi))
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// let a = SNum::make_from_polar(2_f32, 0.5_f32);
+    /// let (r, j) = a.get();
+    /// assert!((r-2_f32*0.5_f32.cosh()).abs() < 0.00001 && (j-2_f32*0.5_f32.sinh()).abs() < 0.00001);
+    /// ```
+    pub fn make_from_polar(rho:f32, phi:f32)->Self{ Self{r:rho*phi.cosh(), j:rho*phi.sinh()} }
+    ///The function that creates the Lorentz boost with the rapidity 'phi': cosh(phi) + j·sinh(phi). The event (t, x) is the number t + j·x (c = 1), and the boost is applied by multiplication
+    ///
+    ///Функция, создающая преобразование Лоренца с быстротой 'phi': cosh(phi) + j·sinh(phi). Событие (t, x) - это число t + j·x (c = 1), а преобразование применяется умножением
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// let b = SNum::make_boost(0.7_f32);
+    /// let e = SNum::make(3_f32, 1_f32);
+    /// //Интервал сохраняется
+    /// assert!((b.mult_s(e.clone()).norm()-e.norm()).abs() < 0.00001);
+    /// //Композиция бустов складывает быстроты
+    /// let c = b.mult_s(SNum::make_boost(-0.2_f32));
+    /// assert!((c.rapidity()-0.5_f32).abs() < 0.00001);
+    /// ```
+    pub fn make_boost(phi:f32)->Self{ Self::make_from_polar(1_f32, phi) }
+    ///The function that creates the Lorentz boost with the velocity 'beta' (in units of c, |beta| < 1)
+    ///
+    ///Функция, создающая преобразование Лоренца со скоростью 'beta' (в единицах c, |beta| < 1)
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// //Релятивистское сложение скоростей
+    /// let c = SNum::make_boost_v(0.6_f32).mult_s(SNum::make_boost_v(0.8_f32));
+    /// assert!((c.velocity()-1.4_f32/1.48_f32).abs() < 0.00001);
+    /// //Лоренц-фактор
+    /// let (gamma, _) = SNum::make_boost_v(0.6_f32).get();
+    /// assert!((gamma-1.25_f32).abs() < 0.00001);
+    /// ```
+    pub fn make_boost_v(beta:f32)->Self{ Self::make_boost(beta.atanh()) }
+    ///The method for cloning a split-complex number
+    ///
+    /// Метод для клонирования расщепляемого комплексного числа
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> SNum{ SNum{r:self.r, j:self.j} }
+    ///The method that returns the coefficients of a split-complex number
+    ///
+    /// Метод, возвращающий коэффициенты расщепляемого комплексного числа
+    pub fn get(&self) -> (f32, f32){ (self.r, self.j) }
+    ///The method that returns the conjugate number a - b·j
+    ///
+    /// Метод, возвращающий сопряженное число a - b·j
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// assert!(SNum::make(1_f32, -1_f32)==SNum::make(1_f32, 1_f32).conj());
+    /// ```
+    pub fn conj(&self) -> SNum{ SNum{r:self.r, j:-self.j} }
+    ///The method that returns the Minkowski norm z·z* = a² - b². It can be negative or zero for non-zero numbers
+    ///
+    /// Метод, возвращающий норму Минковского z·z* = a² - b². Она может быть отрицательной или нулевой для ненулевых чисел
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// assert_eq!(5_f32, SNum::make(3_f32, 2_f32).norm());
+    /// assert_eq!(-5_f32, SNum::make(2_f32, 3_f32).norm());
+    /// //Световой конус
+    /// assert_eq!(0_f32, SNum::make(1_f32, 1_f32).norm());
+    /// ```
+    pub fn norm(&self) -> f32{ self.r*self.r - self.j*self.j }
+    ///The method that returns the module: the square root of the absolute value of the norm
+    ///
+    /// Метод, возвращающий модуль: квадратный корень из абсолютного значения нормы
+    pub fn modl(&self) -> f32{ self.norm().abs().powf(0.5) }
+    ///The method that returns the rapidity (hyperbolic argument) atanh(b/a). It is defined for numbers inside the light cone (|a| > |b|)
+    ///
+    /// Метод, возвращающий быстроту (гиперболический аргумент) atanh(b/a). Определен для чисел внутри светового конуса (|a| > |b|)
+    pub fn rapidity(&self) -> f32{ (self.j/self.r).atanh() }
+    ///The method that returns the velocity b/a of the boost or the event
+    ///
+    /// Метод, возвращающий скорость b/a буста или события
+    pub fn velocity(&self) -> f32{ self.j/self.r }
+    ///The method that returns the hyperbolic polar form (rho, phi) with the number equal to rho·(cosh(phi) + j·sinh(phi)). It exists only in the right sector a > |b|, otherwise None is returned
+    ///
+    /// Метод, возвращающий гиперболическую полярную форму (rho, phi), такую что число равно rho·(cosh(phi) + j·sinh(phi)). Она существует только в правом секторе a > |b|, иначе возвращается None
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// let (rho, phi) = SNum::make(5_f32, 3_f32).to_polar().unwrap();
+    /// assert!((rho-4_f32).abs() < 0.00001 && (phi-(0.6_f32).atanh()).abs() < 0.00001);
+    /// assert!(SNum::make(-5_f32, 3_f32).to_polar().is_none());
+    /// assert!(SNum::make(1_f32, 1_f32).to_polar().is_none());
+    /// ```
+    pub fn to_polar(&self) -> Option<(f32, f32)>{
+        if self.r > self.j.abs() {
+            Some((self.modl(), self.rapidity()))
+        } else {
+            None
+        }
+    }
+    ///The method that returns the sum of a split-complex and a real number
+    ///
+    /// Метод, возвращающий сумму расщепляемого комплексного и действительного числа
+    pub fn add_r(&self, v:f32) -> SNum{ SNum{r:self.r + v, j:self.j} }
+    ///The method that returns the sum of split-complex numbers
+    ///
+    ///Метод, возвращающий сумму расщепляемых комплексных чисел
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// let c = SNum::make(6_f32, 2_f32).add_s(SNum::make(4_f32, 8_f32));
+    /// assert!(SNum::make(10_f32, 10_f32)==c);
+    /// ```
+    pub fn add_s(&self, v:SNum) -> SNum{ SNum{r:self.r + v.r, j:self.j + v.j} }
+    /// The method that returns the product of split-complex and real numbers
+    ///
+    /// Метод, возвращающий произведение расщепляемого комплексного и действительного чисел
+    pub fn mult_r(&self, v:f32) -> SNum{ SNum{r:self.r*v, j:self.j*v} }
+    /// The method that returns the product of split-complex numbers
+    ///
+    /// Метод, возвращающий произведение расщепляемых комплексных чисел
+    ///
+    /// # Example
+    /// ```
+    /// use tmn::split_complex::SNum;
+    /// let c = SNum::make(3_f32, 2_f32).mult_s(SNum::make(5_f32, 3_f32));
+    /// assert!(SNum::make(21_f32, 19_f32) == c);
+    /// //j² = 1
+    /// assert!(SNum::make(1_f32, 0_f32) == SNum::make(0_f32, 1_f32).mult_s(SNum::make(0_f32, 1_f32)));
+    /// //Делители нуля
+    /// assert!(SNum::make_zero() == SNum::make(1_f32, 1_f32).mult_s(SNum::make(1_f32, -1_f32)));
+    /// ```
+    pub fn mult_s(&self, v:SNum) -> SNum{
+        SNum{
+            r:self.r*v.r + self.j*v.j,
+            j:self.r*v.j + self.j*v.r
+        }
+    }
+    ///The method that returns the inverse number z*/(z·z*). Numbers on the light cone (zero norm) have no inverse, NaN is returned
+    ///
+    /// Метод, возвращающий обратное число z*/(z·z*). У чисел на световом конусе (с нулевой нормой) обратного нет, возвращается NaN
+    ///
+    /// # Example
+    /// ```
+    /// use tmn::split_complex::SNum;
+    /// assert!(SNum::make(3_f32/5_f32, -2_f32/5_f32) == SNum::make(3_f32, 2_f32).inv());
+    /// let (r, _) = SNum::make(2_f32, -2_f32).inv().get();
+    /// assert!(r.is_nan());
+    /// ```
+    pub fn inv(&self) -> SNum{
+        let n = self.norm();
+        if n == 0_f32 {
+            return SNum{r:f32::NAN, j:f32::NAN};
+        }
+        self.conj().mult_r(1_f32/n)
+    }
+    ///The method for dividing split-complex numbers
+    ///
+    /// Метод для деления расщепляемых комплексных чисел
+    ///
+    /// # Example
+    /// ```
+    /// use tmn::split_complex::SNum;
+    /// let a = SNum::make(21_f32, 19_f32);
+    /// assert!(SNum::make(3_f32, 2_f32) == a.div_s(SNum::make(5_f32, 3_f32)));
+    /// ```
+    pub fn div_s(&self, v:SNum) -> SNum{ self.mult_s(v.inv()) }
+    ///The method for setting values to specific coefficients
+    ///
+    /// Метод для установки значений в конкретные коэффициенты
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex;
+    /// use tmn::split_complex::SNum;
+    /// let a = SNum::make(1_f32, 2_f32).set(split_complex::R|split_complex::J, 3_f32);
+    /// assert!(SNum::make(3_f32, 3_f32)== a);
+    /// ```
+    pub fn set(&self, c:u8, v:f32) -> Self{
+        let mut ret = self.clone();
+        if cassette::eq(c, 0){
+            ret.r = v;
+        }
+        if cassette::eq(c, 1){
+            ret.j = v;
+        }
+        ret
+    }
+}
+
+impl PartialEq for SNum {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Neg for SNum {
+    type Output = Self;
+    ///Redefined negative operator
+    ///
+    ///Переопределенный оператор отрицательного значения
+    ///
+    /// # Example
+    ///```
+    /// use tmn::split_complex::SNum;
+    /// assert!(SNum::make(-3_f32, 4_f32) == -SNum::make(3_f32, -4_f32));
+    /// ```
+    fn neg(self) -> Self::Output {
+        self.mult_r(-1_f32)
+    }
+}